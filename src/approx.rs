@@ -0,0 +1,200 @@
+use std::cmp::Ordering;
+
+use crate::{Matrix, Vector};
+use crate::number::Real;
+
+// ======== Ulps ===============================================================
+
+/// Distance in units in the last place, i.e. the number of representable
+/// values between two floats.
+pub trait Ulps: Real {
+    /// `u64::MAX` if either is NaN.
+    fn ulps(self, other: Self) -> u64;
+}
+
+macro_rules! impl_ulps {
+    ($($type:ident $int:ident $wide:ident),*) => {
+        $(
+            impl Ulps for $type {
+                fn ulps(self, other: Self) -> u64 {
+                    if self.is_nan() || other.is_nan() {
+                        return u64::MAX;
+                    }
+                    // map the sign-magnitude bits onto a monotonic integer
+                    // line, so that -0.0 and 0.0 are the same point
+                    let key = |value: $type| {
+                        let bits = value.to_bits() as $int;
+                        if bits < 0 { -((bits & $int::MAX) as $wide) } else { bits as $wide }
+                    };
+                    let distance = (key(self) - key(other)).unsigned_abs();
+                    distance.try_into().unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_ulps!(f32 i32 i64, f64 i64 i128);
+
+/// Absolute difference if `lhs` and `rhs` are not within `abs_eps` or
+/// within `rel_eps` relative to the larger magnitude. Infinities only match
+/// themselves.
+#[inline]
+fn approx_diff<T: Real>(lhs: T, rhs: T, abs_eps: T, rel_eps: T) -> Option<T> {
+    if lhs == rhs {
+        return None;
+    }
+    let diff = (lhs - rhs).abs();
+    if !lhs.is_finite() || !rhs.is_finite() {
+        return Some(diff);
+    }
+    let largest = lhs.abs().max(rhs.abs());
+    if diff <= abs_eps || diff <= rel_eps * largest {
+        None
+    } else {
+        Some(diff)
+    }
+}
+
+/// Index and difference of the worst mismatch. NaN is worse than anything.
+fn worst_mismatch<T, D, F>(lhs: &[T], rhs: &[T], mut f: F) -> Option<(usize, D)>
+where T: Copy, D: PartialOrd + Copy, F: FnMut(T, T) -> Option<D> {
+    let mut worst: Option<(usize, D)> = None;
+
+    for (index, (&lhs, &rhs)) in lhs.iter().zip(rhs.iter()).enumerate() {
+        if let Some(diff) = f(lhs, rhs) {
+            #[allow(clippy::eq_op)]
+            let replace = match worst {
+                None => true,
+                Some((_, max)) => max == max && !matches!(diff.partial_cmp(&max), Some(Ordering::Less | Ordering::Equal)),
+            };
+            if replace {
+                worst = Some((index, diff));
+            }
+        }
+    }
+
+    worst
+}
+
+// ======== Matrix/Vector ======================================================
+
+impl<const X: usize, const Y: usize, T: Real> Matrix<X, Y, T>
+where [T; X * Y]: Sized
+{
+    /// Element-wise `|a - b| <= abs_eps || |a - b| <= rel_eps * max(|a|, |b|)`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, abs_eps: T, rel_eps: T) -> bool {
+        self.approx_mismatch(other, abs_eps, rel_eps).is_none()
+    }
+
+    /// `(x, y, difference)` of the worst element that isn't `approx_eq()`.
+    #[inline]
+    pub fn approx_mismatch(&self, other: &Self, abs_eps: T, rel_eps: T) -> Option<(usize, usize, T)> {
+        worst_mismatch(self.data(), other.data(), |lhs, rhs| approx_diff(lhs, rhs, abs_eps, rel_eps))
+            .map(|(index, diff)| (index % X, index / X, diff))
+    }
+
+    /// Element-wise at most `max_ulps` representable values apart.
+    #[inline]
+    pub fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool
+    where T: Ulps {
+        self.ulps_mismatch(other, max_ulps).is_none()
+    }
+
+    /// `(x, y, ulps)` of the worst element that isn't `ulps_eq()`.
+    #[inline]
+    pub fn ulps_mismatch(&self, other: &Self, max_ulps: u64) -> Option<(usize, usize, u64)>
+    where T: Ulps {
+        worst_mismatch(self.data(), other.data(), |lhs, rhs| Some(lhs.ulps(rhs)).filter(|&ulps| ulps > max_ulps))
+            .map(|(index, ulps)| (index % X, index / X, ulps))
+    }
+}
+
+impl<const N: usize, T: Real> Vector<N, T> {
+    /// Element-wise `|a - b| <= abs_eps || |a - b| <= rel_eps * max(|a|, |b|)`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, abs_eps: T, rel_eps: T) -> bool {
+        self.approx_mismatch(other, abs_eps, rel_eps).is_none()
+    }
+
+    /// `(index, difference)` of the worst element that isn't `approx_eq()`.
+    #[inline]
+    pub fn approx_mismatch(&self, other: &Self, abs_eps: T, rel_eps: T) -> Option<(usize, T)> {
+        worst_mismatch(self.data(), other.data(), |lhs, rhs| approx_diff(lhs, rhs, abs_eps, rel_eps))
+    }
+
+    /// Element-wise at most `max_ulps` representable values apart.
+    #[inline]
+    pub fn ulps_eq(&self, other: &Self, max_ulps: u64) -> bool
+    where T: Ulps {
+        self.ulps_mismatch(other, max_ulps).is_none()
+    }
+
+    /// `(index, ulps)` of the worst element that isn't `ulps_eq()`.
+    #[inline]
+    pub fn ulps_mismatch(&self, other: &Self, max_ulps: u64) -> Option<(usize, u64)>
+    where T: Ulps {
+        worst_mismatch(self.data(), other.data(), |lhs, rhs| Some(lhs.ulps(rhs)).filter(|&ulps| ulps > max_ulps))
+    }
+}
+
+// ======== Macros =============================================================
+
+/// `assert_matrix_approx_eq!(left, right, abs_eps, rel_eps)` or
+/// `assert_matrix_approx_eq!(left, right, ulps = max_ulps)`. Reports the
+/// worst element on failure.
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        match (&$left, &$right, $ulps) {
+            (left, right, max_ulps) => {
+                if let Some((x, y, ulps)) = left.ulps_mismatch(right, max_ulps) {
+                    panic!(
+                        "assertion `left ≈ right` failed: element ({}, {}) is {} ulps apart (max {}): {:?} vs {:?}\n  left: {}\n right: {}",
+                        x, y, ulps, max_ulps, left[(x, y)], right[(x, y)], left, right);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $abs_eps:expr, $rel_eps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some((x, y, diff)) = left.approx_mismatch(right, $abs_eps, $rel_eps) {
+                    panic!(
+                        "assertion `left ≈ right` failed: element ({}, {}) differs by {:?}: {:?} vs {:?}\n  left: {}\n right: {}",
+                        x, y, diff, left[(x, y)], right[(x, y)], left, right);
+                }
+            }
+        }
+    };
+}
+
+/// `assert_vector_approx_eq!(left, right, abs_eps, rel_eps)` or
+/// `assert_vector_approx_eq!(left, right, ulps = max_ulps)`. Reports the
+/// worst element on failure.
+#[macro_export]
+macro_rules! assert_vector_approx_eq {
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        match (&$left, &$right, $ulps) {
+            (left, right, max_ulps) => {
+                if let Some((index, ulps)) = left.ulps_mismatch(right, max_ulps) {
+                    panic!(
+                        "assertion `left ≈ right` failed: element {} is {} ulps apart (max {}): {:?} vs {:?}\n  left: {}\n right: {}",
+                        index, ulps, max_ulps, left[index], right[index], left, right);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $abs_eps:expr, $rel_eps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some((index, diff)) = left.approx_mismatch(right, $abs_eps, $rel_eps) {
+                    panic!(
+                        "assertion `left ≈ right` failed: element {} differs by {:?}: {:?} vs {:?}\n  left: {}\n right: {}",
+                        index, diff, left[index], right[index], left, right);
+                }
+            }
+        }
+    };
+}
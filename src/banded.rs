@@ -0,0 +1,465 @@
+use crate::{Matrix, Vector};
+use crate::number::Number;
+use crate::ops::Dot;
+
+// ======== Tridiagonal ========================================================
+
+/// Square `N x N` matrix that only stores its main diagonal and the
+/// diagonals directly below and above it.
+///
+/// All three diagonals are stored as arrays of length `N`. `lower[0]` and
+/// `upper[N - 1]` lie outside of the matrix and are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<const N: usize, T: Number=f64> {
+    lower: Box<[T; N]>,
+    diag:  Box<[T; N]>,
+    upper: Box<[T; N]>,
+}
+
+impl<const N: usize, T: Number> Tridiagonal<N, T> {
+    pub const N: usize = N;
+    pub const SHAPE: [usize; 2] = [N, N];
+
+    #[inline]
+    pub fn new(lower: [T; N], diag: [T; N], upper: [T; N]) -> Self {
+        Self {
+            lower: Box::new(lower),
+            diag:  Box::new(diag),
+            upper: Box::new(upper),
+        }
+    }
+
+    #[inline]
+    pub fn lower(&self) -> &[T; N] {
+        &self.lower
+    }
+
+    #[inline]
+    pub fn diag(&self) -> &[T; N] {
+        &self.diag
+    }
+
+    #[inline]
+    pub fn upper(&self) -> &[T; N] {
+        &self.upper
+    }
+
+    #[inline]
+    pub fn lower_mut(&mut self) -> &mut [T; N] {
+        &mut self.lower
+    }
+
+    #[inline]
+    pub fn diag_mut(&mut self) -> &mut [T; N] {
+        &mut self.diag
+    }
+
+    #[inline]
+    pub fn upper_mut(&mut self) -> &mut [T; N] {
+        &mut self.upper
+    }
+
+    /// Returns `None` for coordinates outside of the three stored diagonals.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= N || y >= N {
+            None
+        } else if x + 1 == y {
+            Some(&self.lower[y])
+        } else if x == y {
+            Some(&self.diag[y])
+        } else if x == y + 1 {
+            Some(&self.upper[y])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= N || y >= N {
+            None
+        } else if x + 1 == y {
+            Some(&mut self.lower[y])
+        } else if x == y {
+            Some(&mut self.diag[y])
+        } else if x == y + 1 {
+            Some(&mut self.upper[y])
+        } else {
+            None
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<N, N, T>
+    where [T; N * N]: Sized {
+        let mut matrix = Matrix::default();
+        let data = matrix.data_mut();
+
+        for y in 0..N {
+            let yoffset = y * N;
+            if y > 0 {
+                data[yoffset + y - 1] = self.lower[y];
+            }
+            data[yoffset + y] = self.diag[y];
+            if y + 1 < N {
+                data[yoffset + y + 1] = self.upper[y];
+            }
+        }
+
+        matrix
+    }
+
+    pub fn to_banded(&self) -> Banded<N, 1, 1, T>
+    where [T; (1usize + 1usize + 1) * N]: Sized {
+        let mut res = Banded::<N, 1, 1, T>::default();
+        let data = res.data_mut();
+
+        for y in 0..N {
+            let yoffset = y * 3;
+            data[yoffset]     = self.lower[y];
+            data[yoffset + 1] = self.diag[y];
+            data[yoffset + 2] = self.upper[y];
+        }
+
+        res
+    }
+
+    /// Solves `self · x = rhs` using the Thomas algorithm in O(N).
+    ///
+    /// No pivoting is performed, so this is only stable for diagonally
+    /// dominant or symmetric positive definite matrices. Returns `None` if a
+    /// zero pivot is encountered.
+    pub fn solve(&self, rhs: &Vector<N, T>) -> Option<Vector<N, T>> {
+        let mut upper = Box::new([T::default(); N]);
+        let mut res = rhs.clone();
+        let data = res.data_mut();
+
+        for index in 0..N {
+            let mut pivot = self.diag[index];
+            if index > 0 {
+                let lower = self.lower[index];
+                pivot -= lower * upper[index - 1];
+                let prev = data[index - 1];
+                data[index] -= lower * prev;
+            }
+
+            if pivot == T::ZERO {
+                return None;
+            }
+
+            upper[index] = self.upper[index] / pivot;
+            data[index] /= pivot;
+        }
+
+        for index in (0..N.saturating_sub(1)).rev() {
+            let next = data[index + 1];
+            data[index] -= upper[index] * next;
+        }
+
+        Some(res)
+    }
+}
+
+impl<const N: usize, T: Number> Default for Tridiagonal<N, T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            lower: Box::new([T::default(); N]),
+            diag:  Box::new([T::default(); N]),
+            upper: Box::new([T::default(); N]),
+        }
+    }
+}
+
+impl<const N: usize, T: Number> From<&Matrix<N, N, T>> for Tridiagonal<N, T>
+where [T; N * N]: Sized
+{
+    /// Copies the three central diagonals, everything else is dropped.
+    #[inline]
+    fn from(value: &Matrix<N, N, T>) -> Self {
+        let mut res = Self::default();
+
+        for y in 0..N {
+            if y > 0 {
+                res.lower[y] = *value.index(y - 1, y);
+            }
+            res.diag[y] = *value.index(y, y);
+            if y + 1 < N {
+                res.upper[y] = *value.index(y + 1, y);
+            }
+        }
+
+        res
+    }
+}
+
+impl<const N: usize, T: Number> From<&Tridiagonal<N, T>> for Matrix<N, N, T>
+where [T; N * N]: Sized
+{
+    #[inline]
+    fn from(value: &Tridiagonal<N, T>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for &Tridiagonal<N, T> {
+    type Output = Vector<N, T>;
+
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        let rhs = rhs.data();
+        let mut data = Box::new([T::default(); N]);
+
+        for (y, value) in data.iter_mut().enumerate() {
+            let mut acc = self.diag[y] * rhs[y];
+            if y > 0 {
+                acc += self.lower[y] * rhs[y - 1];
+            }
+            if y + 1 < N {
+                acc += self.upper[y] * rhs[y + 1];
+            }
+            *value = acc;
+        }
+
+        Vector::from(data)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<Vector<N, T>> for &Tridiagonal<N, T> {
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+        self.dot(&rhs)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for Tridiagonal<N, T> {
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        (&self).dot(rhs)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<Vector<N, T>> for Tridiagonal<N, T> {
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+        (&self).dot(&rhs)
+    }
+}
+
+// ======== Banded =============================================================
+
+/// Square `N x N` matrix with `KL` sub-diagonals and `KU` super-diagonals.
+///
+/// Every row stores `KL + KU + 1` values, the diagonal element of row `y` is
+/// at offset `KL`. Slots that would lie outside of the matrix (in the first
+/// `KL` and last `KU` rows) are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<const N: usize, const KL: usize, const KU: usize, T: Number=f64>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    data: Box<[T; (KL + KU + 1) * N]>
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    pub const N: usize = N;
+    pub const KL: usize = KL;
+    pub const KU: usize = KU;
+    pub const WIDTH: usize = KL + KU + 1;
+    pub const SHAPE: [usize; 2] = [N, N];
+
+    #[inline]
+    const fn band_index(x: usize, y: usize) -> usize {
+        y * (KL + KU + 1) + x + KL - y
+    }
+
+    #[inline]
+    fn offset(x: usize, y: usize) -> Option<usize> {
+        if x >= N || y >= N || x + KL < y || x > y + KU {
+            return None;
+        }
+        Some(Self::band_index(x, y))
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[T; (KL + KU + 1) * N] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T; (KL + KU + 1) * N] {
+        &mut self.data
+    }
+
+    /// Returns `None` for coordinates outside of the band.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        Self::offset(x, y).map(|index| &self.data[index])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        Self::offset(x, y).map(|index| &mut self.data[index])
+    }
+
+    pub fn to_matrix(&self) -> Matrix<N, N, T>
+    where [T; N * N]: Sized {
+        let mut matrix = Matrix::default();
+        let data = matrix.data_mut();
+
+        for y in 0..N {
+            let yoffset = y * N;
+            for x in y.saturating_sub(KL)..N.min(y + KU + 1) {
+                data[yoffset + x] = self.data[Self::band_index(x, y)];
+            }
+        }
+
+        matrix
+    }
+
+    /// Solves `self · x = rhs` using a banded LU decomposition in
+    /// O(N · KL · KU).
+    ///
+    /// No pivoting is performed (pivoting would require `KL` additional
+    /// super-diagonals of storage), so this is only stable for diagonally
+    /// dominant or symmetric positive definite matrices. Returns `None` if a
+    /// zero pivot is encountered.
+    pub fn solve(&self, rhs: &Vector<N, T>) -> Option<Vector<N, T>> {
+        let mut lu = self.data.clone();
+
+        for k in 0..N {
+            let pivot = lu[Self::band_index(k, k)];
+            if pivot == T::ZERO {
+                return None;
+            }
+
+            let x_end = N.min(k + KU + 1);
+            for y in k + 1..N.min(k + KL + 1) {
+                let factor = lu[Self::band_index(k, y)] / pivot;
+                lu[Self::band_index(k, y)] = factor;
+                for x in k + 1..x_end {
+                    let value = lu[Self::band_index(x, k)];
+                    lu[Self::band_index(x, y)] -= factor * value;
+                }
+            }
+        }
+
+        let mut res = rhs.clone();
+        let data = res.data_mut();
+
+        for y in 0..N {
+            let mut value = data[y];
+            for x in y.saturating_sub(KL)..y {
+                value -= lu[Self::band_index(x, y)] * data[x];
+            }
+            data[y] = value;
+        }
+
+        for y in (0..N).rev() {
+            let mut value = data[y];
+            for x in y + 1..N.min(y + KU + 1) {
+                value -= lu[Self::band_index(x, y)] * data[x];
+            }
+            data[y] = value / lu[Self::band_index(y, y)];
+        }
+
+        Some(res)
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> Default for Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self { data: Box::new([T::default(); (KL + KU + 1) * N]) }
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> From<&Matrix<N, N, T>> for Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized, [T; N * N]: Sized
+{
+    /// Copies the values inside of the band, everything else is dropped.
+    fn from(value: &Matrix<N, N, T>) -> Self {
+        let mut res = Self::default();
+        let mtx = value.data();
+
+        for y in 0..N {
+            let yoffset = y * N;
+            for x in y.saturating_sub(KL)..N.min(y + KU + 1) {
+                res.data[Self::band_index(x, y)] = mtx[yoffset + x];
+            }
+        }
+
+        res
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> From<&Banded<N, KL, KU, T>> for Matrix<N, N, T>
+where [T; (KL + KU + 1) * N]: Sized, [T; N * N]: Sized
+{
+    #[inline]
+    fn from(value: &Banded<N, KL, KU, T>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> Dot<&Vector<N, T>> for &Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    type Output = Vector<N, T>;
+
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        let rhs = rhs.data();
+        let mut data = Box::new([T::default(); N]);
+
+        for (y, value) in data.iter_mut().enumerate() {
+            let start = y.saturating_sub(KL);
+            let end = N.min(y + KU + 1);
+            let offset = Banded::<N, KL, KU, T>::band_index(start, y);
+            let band = &self.data[offset..offset + end - start];
+            *value = band.iter().zip(&rhs[start..end]).fold(T::ZERO, |acc, (&a, &b)| acc + a * b);
+        }
+
+        Vector::from(data)
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> Dot<Vector<N, T>> for &Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+        self.dot(&rhs)
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> Dot<&Vector<N, T>> for Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        (&self).dot(rhs)
+    }
+}
+
+impl<const N: usize, const KL: usize, const KU: usize, T: Number> Dot<Vector<N, T>> for Banded<N, KL, KU, T>
+where [T; (KL + KU + 1) * N]: Sized
+{
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+        (&self).dot(&rhs)
+    }
+}
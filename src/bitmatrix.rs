@@ -0,0 +1,357 @@
+use std::ops::{Add, Mul, AddAssign, MulAssign, BitAndAssign, BitXorAssign};
+use std::fmt::Display;
+
+use crate::{Matrix, ModP};
+use crate::ops::Dot;
+
+#[inline]
+pub(crate) const fn bit_words(bits: usize) -> usize {
+    bits.div_ceil(64)
+}
+
+// ======== BitMatrix ==========================================================
+
+/// Matrix over GF(2), packing every row into `u64` words.
+///
+/// `+` is XOR and `*` is the element-wise AND, `dot()` is the matrix product
+/// over GF(2). Unused bits of the last word of a row are always zero.
+///
+/// Bounds spell out the words per row as `X.div_ceil(64)` so that generic
+/// code outside of this crate can name them.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct BitMatrix<const X: usize, const Y: usize>
+where [u64; Y * X.div_ceil(64)]: Sized
+{
+    data: Box<[u64; Y * X.div_ceil(64)]>
+}
+
+impl<const X: usize, const Y: usize> BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized
+{
+    pub const X: usize = X;
+    pub const Y: usize = Y;
+    pub const SHAPE: [usize; 2] = [Y, X];
+    pub const WORDS: usize = bit_words(X);
+
+    #[inline]
+    pub fn new() -> Self {
+        Self { data: Box::new([0; Y * X.div_ceil(64)]) }
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[u64; Y * X.div_ceil(64)] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn row_words(&self, y: usize) -> &[u64] {
+        &self.data[y * Self::WORDS..(y + 1) * Self::WORDS]
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if x >= X || y >= Y {
+            return None;
+        }
+        Some(self.data[y * Self::WORDS + x / 64] >> (x % 64) & 1 != 0)
+    }
+
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(x < X && y < Y, "index out of bounds");
+        let word = &mut self.data[y * Self::WORDS + x / 64];
+        if value {
+            *word |= 1 << (x % 64);
+        } else {
+            *word &= !(1 << (x % 64));
+        }
+    }
+
+    #[inline]
+    pub fn flip(&mut self, x: usize, y: usize) {
+        assert!(x < X && y < Y, "index out of bounds");
+        self.data[y * Self::WORDS + x / 64] ^= 1 << (x % 64);
+    }
+
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) {
+        for index in 0..Self::WORDS {
+            self.data.swap(y1 * Self::WORDS + index, y2 * Self::WORDS + index);
+        }
+    }
+
+    /// Row `dest` += row `src`.
+    #[inline]
+    pub fn xor_row(&mut self, src: usize, dest: usize) {
+        for index in 0..Self::WORDS {
+            self.data[dest * Self::WORDS + index] ^= self.data[src * Self::WORDS + index];
+        }
+    }
+
+    pub fn transpose(&self) -> BitMatrix<Y, X>
+    where [u64; X * Y.div_ceil(64)]: Sized {
+        let mut res = BitMatrix::<Y, X>::new();
+        for y in 0..Y {
+            for x in 0..X {
+                if self.data[y * Self::WORDS + x / 64] >> (x % 64) & 1 != 0 {
+                    res.data[x * BitMatrix::<Y, X>::WORDS + y / 64] |= 1 << (y % 64);
+                }
+            }
+        }
+        res
+    }
+
+    /// Rank over GF(2).
+    pub fn rank(&self) -> usize {
+        let mut matrix = self.clone();
+        let mut rank = 0;
+
+        for x in 0..X {
+            let Some(pivot) = (rank..Y).find(|&y| matrix.get(x, y) == Some(true)) else {
+                continue;
+            };
+            matrix.swap_rows(pivot, rank);
+            for y in 0..Y {
+                if y != rank && matrix.get(x, y) == Some(true) {
+                    matrix.xor_row(rank, y);
+                }
+            }
+            rank += 1;
+        }
+
+        rank
+    }
+
+    pub fn to_matrix(&self) -> Matrix<X, Y, ModP<2>>
+    where [ModP<2>; X * Y]: Sized {
+        let mut data = Box::new([ModP::new(0); X * Y]);
+        for y in 0..Y {
+            for x in 0..X {
+                if self.data[y * Self::WORDS + x / 64] >> (x % 64) & 1 != 0 {
+                    data[y * X + x] = ModP::new(1);
+                }
+            }
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<const N: usize> BitMatrix<N, N>
+where [u64; N * N.div_ceil(64)]: Sized
+{
+    pub fn identity() -> Self {
+        let mut res = Self::new();
+        for index in 0..N {
+            res.set(index, index, true);
+        }
+        res
+    }
+
+    /// Gauss-Jordan elimination over GF(2). Returns `None` for singular
+    /// matrices.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let mut matrix = self.clone();
+        let mut inverse = Self::identity();
+
+        for x in 0..N {
+            let pivot = (x..N).find(|&y| matrix.get(x, y) == Some(true))?;
+            matrix.swap_rows(pivot, x);
+            inverse.swap_rows(pivot, x);
+            for y in 0..N {
+                if y != x && matrix.get(x, y) == Some(true) {
+                    matrix.xor_row(x, y);
+                    inverse.xor_row(x, y);
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> bool {
+        self.rank() == N
+    }
+}
+
+impl<const X: usize, const Y: usize> Default for BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const X: usize, const Y: usize> From<[[bool; X]; Y]> for BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized
+{
+    fn from(value: [[bool; X]; Y]) -> Self {
+        let mut res = Self::new();
+        for (y, row) in value.iter().enumerate() {
+            for (x, &bit) in row.iter().enumerate() {
+                if bit {
+                    res.set(x, y, true);
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<const X: usize, const Y: usize> From<&Matrix<X, Y, ModP<2>>> for BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized, [ModP<2>; X * Y]: Sized
+{
+    fn from(value: &Matrix<X, Y, ModP<2>>) -> Self {
+        let mut res = Self::new();
+        for (index, bit) in value.iter().enumerate() {
+            if bit.value() != 0 {
+                res.set(index % X, index / X, true);
+            }
+        }
+        res
+    }
+}
+
+impl<const X: usize, const Y: usize> Display for BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..Y {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for x in 0..X {
+                f.write_str(if self.get(x, y) == Some(true) { "1" } else { "0" })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_bit_ops {
+    ($trait:ident $trait_assign:ident $op:ident $op_assign:ident $word_op:ident) => {
+        impl<const X: usize, const Y: usize> $trait_assign<&BitMatrix<X, Y>> for BitMatrix<X, Y>
+        where [u64; Y * X.div_ceil(64)]: Sized
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: &BitMatrix<X, Y>) {
+                for (lhs, rhs) in self.data.iter_mut().zip(rhs.data.iter()) {
+                    lhs.$word_op(*rhs);
+                }
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait_assign<BitMatrix<X, Y>> for BitMatrix<X, Y>
+        where [u64; Y * X.div_ceil(64)]: Sized
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: BitMatrix<X, Y>) {
+                self.$op_assign(&rhs);
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait<&BitMatrix<X, Y>> for BitMatrix<X, Y>
+        where [u64; Y * X.div_ceil(64)]: Sized
+        {
+            type Output = BitMatrix<X, Y>;
+
+            #[inline]
+            fn $op(mut self, rhs: &BitMatrix<X, Y>) -> Self::Output {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait<BitMatrix<X, Y>> for BitMatrix<X, Y>
+        where [u64; Y * X.div_ceil(64)]: Sized
+        {
+            type Output = BitMatrix<X, Y>;
+
+            #[inline]
+            fn $op(mut self, rhs: BitMatrix<X, Y>) -> Self::Output {
+                self.$op_assign(&rhs);
+                self
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait<&BitMatrix<X, Y>> for &BitMatrix<X, Y>
+        where [u64; Y * X.div_ceil(64)]: Sized
+        {
+            type Output = BitMatrix<X, Y>;
+
+            #[inline]
+            fn $op(self, rhs: &BitMatrix<X, Y>) -> Self::Output {
+                self.clone().$op(rhs)
+            }
+        }
+    };
+}
+
+impl_bit_ops!(Add AddAssign add add_assign bitxor_assign);
+impl_bit_ops!(Mul MulAssign mul mul_assign bitand_assign);
+
+// ======== Dot ================================================================
+
+/// Matrix product over GF(2): `Y x X` times `X x Z` gives `Y x Z`.
+impl<const X: usize, const Y: usize, const Z: usize> Dot<&BitMatrix<Z, X>> for &BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized, [u64; X * Z.div_ceil(64)]: Sized, [u64; Y * Z.div_ceil(64)]: Sized
+{
+    type Output = BitMatrix<Z, Y>;
+
+    fn dot(self, rhs: &BitMatrix<Z, X>) -> Self::Output {
+        let words = BitMatrix::<Z, X>::WORDS;
+        let mut res = BitMatrix::<Z, Y>::new();
+
+        for y in 0..Y {
+            let dest = &mut res.data[y * words..(y + 1) * words];
+            for k in 0..X {
+                if self.data[y * BitMatrix::<X, Y>::WORDS + k / 64] >> (k % 64) & 1 != 0 {
+                    for (dest, src) in dest.iter_mut().zip(rhs.row_words(k)) {
+                        *dest ^= *src;
+                    }
+                }
+            }
+        }
+
+        res
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize> Dot<BitMatrix<Z, X>> for &BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized, [u64; X * Z.div_ceil(64)]: Sized, [u64; Y * Z.div_ceil(64)]: Sized
+{
+    type Output = BitMatrix<Z, Y>;
+
+    #[inline]
+    fn dot(self, rhs: BitMatrix<Z, X>) -> Self::Output {
+        self.dot(&rhs)
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize> Dot<&BitMatrix<Z, X>> for BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized, [u64; X * Z.div_ceil(64)]: Sized, [u64; Y * Z.div_ceil(64)]: Sized
+{
+    type Output = BitMatrix<Z, Y>;
+
+    #[inline]
+    fn dot(self, rhs: &BitMatrix<Z, X>) -> Self::Output {
+        (&self).dot(rhs)
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize> Dot<BitMatrix<Z, X>> for BitMatrix<X, Y>
+where [u64; Y * X.div_ceil(64)]: Sized, [u64; X * Z.div_ceil(64)]: Sized, [u64; Y * Z.div_ceil(64)]: Sized
+{
+    type Output = BitMatrix<Z, Y>;
+
+    #[inline]
+    fn dot(self, rhs: BitMatrix<Z, X>) -> Self::Output {
+        (&self).dot(&rhs)
+    }
+}
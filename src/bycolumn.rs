@@ -1,9 +1,146 @@
 use std::ops::{Index, IndexMut};
 
-use crate::{Matrix, Number, Vector, iter::ColumnIter, ops::{MatrixAggregate, Get, GetMut, Slice}, range::RangeIter};
+use crate::{Matrix, Number, Vector, iter::ColumnIter, ops::{MatrixAggregate, Get, GetMut, Slice, SliceAssign}, range::RangeIter};
 
 // TODO: impl Slice
 
+// ======== SliceAssign ========================================================
+
+// Coordinates are given in column-major order, i.e. swapped.
+macro_rules! impl_slice_assign {
+    ($type:ident $(<$lt:lifetime>)?) => {
+        impl<$($lt,)? const X: usize, const Y: usize, T: Number, RangeX: RangeIter, RangeY: RangeIter> SliceAssign<(RangeY, RangeX)> for $type<$($lt,)? X, Y, T>
+        where [T; X * Y]: Sized, [T; RangeX::LEN * RangeY::LEN]: Sized
+        {
+            type Input = Matrix<{ RangeX::LEN }, { RangeY::LEN }, T>;
+            type Element = T;
+
+            #[inline]
+            fn assign_slice(&mut self, ranges: (RangeY, RangeX), values: &Self::Input) {
+                self.assign_slice(&ranges, values);
+            }
+
+            #[inline]
+            fn fill_slice(&mut self, ranges: (RangeY, RangeX), value: T) {
+                self.fill_slice(&ranges, value);
+            }
+        }
+
+        impl<$($lt,)? const X: usize, const Y: usize, T: Number, RangeX: RangeIter, RangeY: RangeIter> SliceAssign<&(RangeY, RangeX)> for $type<$($lt,)? X, Y, T>
+        where [T; X * Y]: Sized, [T; RangeX::LEN * RangeY::LEN]: Sized
+        {
+            type Input = Matrix<{ RangeX::LEN }, { RangeY::LEN }, T>;
+            type Element = T;
+
+            fn assign_slice(&mut self, (y_range, x_range): &(RangeY, RangeX), values: &Self::Input) {
+                let data = self.matrix.data_mut();
+                let x_iter = x_range.iter();
+                let mut src = values.iter();
+                for y in y_range.iter() {
+                    let yoffset = y * X;
+                    for x in x_iter.clone() {
+                        data[yoffset + x] = *src.next().unwrap();
+                    }
+                }
+            }
+
+            fn fill_slice(&mut self, (y_range, x_range): &(RangeY, RangeX), value: T) {
+                let data = self.matrix.data_mut();
+                let x_iter = x_range.iter();
+                for y in y_range.iter() {
+                    let yoffset = y * X;
+                    for x in x_iter.clone() {
+                        data[yoffset + x] = value;
+                    }
+                }
+            }
+        }
+
+        impl<$($lt,)? const X: usize, const Y: usize, T: Number, const N: usize> SliceAssign<[(usize, usize); N]> for $type<$($lt,)? X, Y, T>
+        where [T; X * Y]: Sized, [T; N]: Sized
+        {
+            type Input = Vector<N, T>;
+            type Element = T;
+
+            #[inline]
+            fn assign_slice(&mut self, coords: [(usize, usize); N], values: &Self::Input) {
+                self.assign_slice(&coords, values);
+            }
+
+            #[inline]
+            fn fill_slice(&mut self, coords: [(usize, usize); N], value: T) {
+                self.fill_slice(&coords, value);
+            }
+        }
+
+        impl<$($lt,)? const X: usize, const Y: usize, T: Number, const N: usize> SliceAssign<&[(usize, usize); N]> for $type<$($lt,)? X, Y, T>
+        where [T; X * Y]: Sized, [T; N]: Sized
+        {
+            type Input = Vector<N, T>;
+            type Element = T;
+
+            #[inline]
+            fn assign_slice(&mut self, coords: &[(usize, usize); N], values: &Self::Input) {
+                let data = self.matrix.data_mut();
+                for (&(x, y), value) in coords.iter().zip(values.iter()) {
+                    data[x * X + y] = *value;
+                }
+            }
+
+            #[inline]
+            fn fill_slice(&mut self, coords: &[(usize, usize); N], value: T) {
+                let data = self.matrix.data_mut();
+                for &(x, y) in coords {
+                    data[x * X + y] = value;
+                }
+            }
+        }
+
+        impl<$($lt,)? const X: usize, const Y: usize, T: Number, const X2: usize, const Y2: usize> SliceAssign<[[(usize, usize); X2]; Y2]> for $type<$($lt,)? X, Y, T>
+        where [T; X * Y]: Sized, [T; X2 * Y2]: Sized
+        {
+            type Input = Matrix<X2, Y2, T>;
+            type Element = T;
+
+            #[inline]
+            fn assign_slice(&mut self, coords: [[(usize, usize); X2]; Y2], values: &Self::Input) {
+                self.assign_slice(&coords, values);
+            }
+
+            #[inline]
+            fn fill_slice(&mut self, coords: [[(usize, usize); X2]; Y2], value: T) {
+                self.fill_slice(&coords, value);
+            }
+        }
+
+        impl<$($lt,)? const X: usize, const Y: usize, T: Number, const X2: usize, const Y2: usize> SliceAssign<&[[(usize, usize); X2]; Y2]> for $type<$($lt,)? X, Y, T>
+        where [T; X * Y]: Sized, [T; X2 * Y2]: Sized
+        {
+            type Input = Matrix<X2, Y2, T>;
+            type Element = T;
+
+            #[inline]
+            fn assign_slice(&mut self, coords: &[[(usize, usize); X2]; Y2], values: &Self::Input) {
+                let data = self.matrix.data_mut();
+                for (&(x, y), value) in coords.iter().flatten().zip(values.iter()) {
+                    data[x * X + y] = *value;
+                }
+            }
+
+            #[inline]
+            fn fill_slice(&mut self, coords: &[[(usize, usize); X2]; Y2], value: T) {
+                let data = self.matrix.data_mut();
+                for &(x, y) in coords.iter().flatten() {
+                    data[x * X + y] = value;
+                }
+            }
+        }
+    };
+}
+
+impl_slice_assign!(IntoByColumn);
+impl_slice_assign!(ByColumnMut<'a>);
+
 // ======== IntoByColumn =======================================================
 
 #[repr(transparent)]
@@ -48,6 +185,13 @@ where [T; X * Y]: Sized {
     }
 
     #[inline]
+    fn aggregate<F, B>(&self, f: F) -> Vector<X, B>
+    where F: FnMut(&mut [T]) -> B, B: Number {
+        aggregate_by_column(&self.matrix, f)
+    }
+
+    #[inline]
+    #[allow(deprecated)]
     fn mean(&self) -> Vector<X, T>
     where T: Ord, [T; X * Y]: Sized {
         mean_by_column(&self.matrix)
@@ -220,6 +364,13 @@ where [T; X * Y]: Sized {
     }
 
     #[inline]
+    fn aggregate<F, B>(&self, f: F) -> Vector<X, B>
+    where F: FnMut(&mut [T]) -> B, B: Number {
+        aggregate_by_column(self.matrix, f)
+    }
+
+    #[inline]
+    #[allow(deprecated)]
     fn mean(&self) -> Vector<X, T>
     where T: Ord, [T; X * Y]: Sized {
         mean_by_column(self.matrix)
@@ -374,6 +525,13 @@ where [T; X * Y]: Sized {
     }
 
     #[inline]
+    fn aggregate<F, B>(&self, f: F) -> Vector<X, B>
+    where F: FnMut(&mut [T]) -> B, B: Number {
+        aggregate_by_column(self.matrix, f)
+    }
+
+    #[inline]
+    #[allow(deprecated)]
     fn mean(&self) -> Vector<X, T>
     where T: Ord, [T; X * Y]: Sized {
         mean_by_column(self.matrix)
@@ -506,6 +664,7 @@ where [T; X * Y]: Sized, [T; X2 * Y2]: Sized
 // ======== Helper Functions ===================================================
 
 #[inline]
+#[deprecated(note = "this computes the median, use `by_column().median()` instead")]
 pub fn mean_by_column<const X: usize, const Y: usize, T: Number>(matrix: &Matrix<X, Y, T>) -> Vector<X, T>
 where [T; X * Y]: Sized, T: Ord {
     let mut iter = matrix.columns();
@@ -523,6 +682,23 @@ where [T; X * Y]: Sized, T: Ord {
     Vector::from(data)
 }
 
+/// Calls `f` with a scratch copy of each column.
+#[inline]
+pub fn aggregate_by_column<F, B, const X: usize, const Y: usize, T: Number>(matrix: &Matrix<X, Y, T>, mut f: F) -> Vector<X, B>
+where F: FnMut(&mut [T]) -> B, B: Number, [T; X * Y]: Sized {
+    let mtx = matrix.data();
+    let mut column = Vec::with_capacity(Y);
+    let mut x = 0;
+    let data = Box::new([(); X].map(|_| {
+        column.clear();
+        column.extend((0..Y).map(|y| mtx[y * X + x]));
+        x += 1;
+        f(&mut column)
+    }));
+
+    Vector::from(data)
+}
+
 #[inline]
 pub fn fold_by_column<F, B, const X: usize, const Y: usize, T: Number>(matrix: &Matrix<X, Y, T>, init: B, mut f: F) -> Vector<X, B>
 where F: FnMut(B, T) -> B, B: Number, [T; X * Y]: Sized {
@@ -0,0 +1,280 @@
+use std::fmt::{Display, Debug};
+
+use crate::{Matrix, Vector, f16, bf16};
+use crate::number::Number;
+
+// ======== LossyFrom ==========================================================
+
+/// Conversion with `as` semantics: truncating, wrapping or saturating as
+/// the case may be.
+pub trait LossyFrom<T> {
+    fn lossy_from(value: T) -> Self;
+}
+
+macro_rules! impl_lossy_from {
+    ($($type:ident)*) => {
+        impl_lossy_from!(@each [$($type)*] $($type)*);
+    };
+    (@each $dsts:tt $($src:ident)*) => {
+        $( impl_lossy_from!(@one $src $dsts); )*
+    };
+    (@one $src:ident [$($dst:ident)*]) => {
+        $(
+            impl LossyFrom<$src> for $dst {
+                #[inline]
+                fn lossy_from(value: $src) -> Self {
+                    value as $dst
+                }
+            }
+        )*
+    };
+}
+
+impl_lossy_from!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+macro_rules! impl_lossy_from_half {
+    ($($type:ident)*) => {
+        $(
+            impl LossyFrom<f32> for $type {
+                #[inline]
+                fn lossy_from(value: f32) -> Self {
+                    $type::from_f32(value)
+                }
+            }
+
+            impl LossyFrom<f64> for $type {
+                #[inline]
+                fn lossy_from(value: f64) -> Self {
+                    $type::from_f64(value)
+                }
+            }
+
+            impl LossyFrom<$type> for f32 {
+                #[inline]
+                fn lossy_from(value: $type) -> Self {
+                    value.to_f32()
+                }
+            }
+
+            impl LossyFrom<$type> for f64 {
+                #[inline]
+                fn lossy_from(value: $type) -> Self {
+                    value.to_f64()
+                }
+            }
+        )*
+    };
+}
+
+impl_lossy_from_half!(f16 bf16);
+
+// ======== TryCastFrom ========================================================
+
+/// Conversion that only succeeds if the value is represented exactly. Floats
+/// with a fractional part don't convert to integers, so round them first.
+pub trait TryCastFrom<T>: Sized {
+    fn try_cast_from(value: T) -> Option<Self>;
+}
+
+macro_rules! impl_try_cast_from {
+    (int [$($src:ident)*] [$($dst:ident)*]) => {
+        impl_try_cast_from!(@each int [$($dst)*] $($src)*);
+    };
+    (signed [$($src:ident)*] float [$($dst:ident)*]) => {
+        impl_try_cast_from!(@each signed_float [$($dst)*] $($src)*);
+    };
+    (unsigned [$($src:ident)*] float [$($dst:ident)*]) => {
+        impl_try_cast_from!(@each unsigned_float [$($dst)*] $($src)*);
+    };
+    (float [$($src:ident)*] int [$($dst:ident)*]) => {
+        impl_try_cast_from!(@each float_int [$($dst)*] $($src)*);
+    };
+    (float [$($src:ident)*] float [$($dst:ident)*]) => {
+        impl_try_cast_from!(@each float_float [$($dst)*] $($src)*);
+    };
+    (@each $kind:ident $dsts:tt $($src:ident)*) => {
+        $( impl_try_cast_from!(@one $kind $src $dsts); )*
+    };
+    (@one $kind:ident $src:ident [$($dst:ident)*]) => {
+        $(
+            impl TryCastFrom<$src> for $dst {
+                #[inline]
+                fn try_cast_from(value: $src) -> Option<Self> {
+                    impl_try_cast_from!(@body $kind value $src $dst)
+                }
+            }
+        )*
+    };
+    (@body int $value:ident $src:ident $dst:ident) => {
+        $dst::try_from($value).ok()
+    };
+    (@body signed_float $value:ident $src:ident $dst:ident) => {
+        impl_try_cast_from!(@int_float ($value as i128).unsigned_abs(), $value, $dst)
+    };
+    (@body unsigned_float $value:ident $src:ident $dst:ident) => {
+        impl_try_cast_from!(@int_float $value as u128, $value, $dst)
+    };
+    (@int_float $mag:expr, $value:ident, $dst:ident) => {{
+        // exact if the significant bits fit into the mantissa
+        let mag = $mag;
+        if mag == 0 || (mag >> mag.trailing_zeros()) >> $dst::MANTISSA_DIGITS == 0 {
+            Some($value as $dst)
+        } else {
+            None
+        }
+    }};
+    (@body float_int $value:ident $src:ident $dst:ident) => {{
+        let bits = $dst::BITS as i32;
+        let (min, max) = if $dst::MIN == 0 {
+            (0.0, (2.0 as $src).powi(bits))
+        } else {
+            let max = (2.0 as $src).powi(bits - 1);
+            (-max, max)
+        };
+        // NaN fails the comparisons
+        if $value >= min && $value < max && $value.trunc() == $value {
+            Some($value as $dst)
+        } else {
+            None
+        }
+    }};
+    (@body float_float $value:ident $src:ident $dst:ident) => {{
+        let result = $value as $dst;
+        if result as $src == $value || $value.is_nan() {
+            Some(result)
+        } else {
+            None
+        }
+    }};
+}
+
+impl_try_cast_from!(
+    int [i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize]
+    [i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize]
+);
+impl_try_cast_from!(signed [i8 i16 i32 i64 i128 isize] float [f32 f64]);
+impl_try_cast_from!(unsigned [u8 u16 u32 u64 u128 usize] float [f32 f64]);
+impl_try_cast_from!(float [f32 f64] int [i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize]);
+impl_try_cast_from!(float [f32 f64] float [f32 f64]);
+
+macro_rules! impl_try_cast_from_half {
+    ($($type:ident)*) => {
+        $(
+            impl TryCastFrom<f32> for $type {
+                #[inline]
+                fn try_cast_from(value: f32) -> Option<Self> {
+                    let result = $type::from_f32(value);
+                    if result.to_f32() == value || value.is_nan() {
+                        Some(result)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl TryCastFrom<f64> for $type {
+                #[inline]
+                fn try_cast_from(value: f64) -> Option<Self> {
+                    let result = $type::from_f64(value);
+                    if result.to_f64() == value || value.is_nan() {
+                        Some(result)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl TryCastFrom<$type> for f32 {
+                #[inline]
+                fn try_cast_from(value: $type) -> Option<Self> {
+                    Some(value.to_f32())
+                }
+            }
+
+            impl TryCastFrom<$type> for f64 {
+                #[inline]
+                fn try_cast_from(value: $type) -> Option<Self> {
+                    Some(value.to_f64())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_cast_from_half!(f16 bf16);
+
+// ======== TryCastError =======================================================
+
+/// The element at `(x, y)` couldn't be converted. For vectors `x` is the
+/// index and `y` is always 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryCastError<T> {
+    pub x: usize,
+    pub y: usize,
+    pub value: T,
+}
+
+impl<T: Display> Display for TryCastError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "element ({}, {}) = {} can't be converted exactly", self.x, self.y, self.value)
+    }
+}
+
+impl<T: Display + Debug> std::error::Error for TryCastError<T> {}
+
+// ======== Matrix/Vector ======================================================
+
+impl<const X: usize, const Y: usize, T: Number> Matrix<X, Y, T>
+where [T; X * Y]: Sized
+{
+    /// Lossless conversion of the element type.
+    #[inline]
+    pub fn cast<U: Number + From<T>>(&self) -> Matrix<X, Y, U>
+    where [U; X * Y]: Sized {
+        self.map(U::from)
+    }
+
+    /// Conversion of the element type with `as` semantics.
+    #[inline]
+    pub fn cast_lossy<U: Number + LossyFrom<T>>(&self) -> Matrix<X, Y, U>
+    where [U; X * Y]: Sized {
+        self.map(U::lossy_from)
+    }
+
+    /// Fails on the first element that can't be converted exactly.
+    pub fn try_cast<U: Number + TryCastFrom<T>>(&self) -> Result<Matrix<X, Y, U>, TryCastError<T>>
+    where [U; X * Y]: Sized {
+        let mut data = Box::new([U::ZERO; X * Y]);
+
+        for (index, (dest, &value)) in data.iter_mut().zip(self.data().iter()).enumerate() {
+            *dest = U::try_cast_from(value).ok_or(TryCastError { x: index % X, y: index / X, value })?;
+        }
+
+        Ok(Matrix::from(data))
+    }
+}
+
+impl<const N: usize, T: Number> Vector<N, T> {
+    /// Lossless conversion of the element type.
+    #[inline]
+    pub fn cast<U: Number + From<T>>(&self) -> Vector<N, U> {
+        self.map(U::from)
+    }
+
+    /// Conversion of the element type with `as` semantics.
+    #[inline]
+    pub fn cast_lossy<U: Number + LossyFrom<T>>(&self) -> Vector<N, U> {
+        self.map(U::lossy_from)
+    }
+
+    /// Fails on the first element that can't be converted exactly.
+    pub fn try_cast<U: Number + TryCastFrom<T>>(&self) -> Result<Vector<N, U>, TryCastError<T>> {
+        let mut data = Box::new([U::ZERO; N]);
+
+        for (index, (dest, &value)) in data.iter_mut().zip(self.data().iter()).enumerate() {
+            *dest = U::try_cast_from(value).ok_or(TryCastError { x: index, y: 0, value })?;
+        }
+
+        Ok(Vector::from(data))
+    }
+}
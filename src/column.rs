@@ -0,0 +1,110 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Matrix, Number, Vector};
+
+// ======== ColumnMut ==========================================================
+
+/// Mutable strided view of a single matrix column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ColumnMut<'a, const X: usize, const Y: usize, T: Number>
+where [T; X * Y]: Sized {
+    matrix: &'a mut Matrix<X, Y, T>,
+    x: usize,
+}
+
+impl<'a, const X: usize, const Y: usize, T: Number> ColumnMut<'a, X, Y, T>
+where [T; X * Y]: Sized {
+    #[inline]
+    pub fn new(matrix: &'a mut Matrix<X, Y, T>, x: usize) -> Option<Self> {
+        if x >= X {
+            return None;
+        }
+        Some(Self { matrix, x })
+    }
+
+    #[inline]
+    pub const fn x(&self) -> usize {
+        self.x
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        Y
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        Y == 0
+    }
+
+    #[inline]
+    pub fn get(&self, y: usize) -> Option<&T> {
+        if y >= Y {
+            return None;
+        }
+        self.matrix.get(self.x, y)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, y: usize) -> Option<&mut T> {
+        if y >= Y {
+            return None;
+        }
+        self.matrix.get_mut(self.x, y)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl std::iter::Iterator<Item = &T> {
+        self.matrix.data()[self.x..].iter().step_by(X)
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl std::iter::Iterator<Item = &mut T> {
+        self.matrix.data_mut()[self.x..].iter_mut().step_by(X)
+    }
+
+    #[inline]
+    pub fn to_vector(&self) -> Vector<Y, T> {
+        self.matrix.column(self.x)
+    }
+
+    #[inline]
+    pub fn assign(&mut self, column: &Vector<Y, T>) {
+        for (dest, src) in self.iter_mut().zip(column.iter()) {
+            *dest = *src;
+        }
+    }
+
+    #[inline]
+    pub fn fill(&mut self, value: T) {
+        for dest in self.iter_mut() {
+            *dest = value;
+        }
+    }
+
+    #[inline]
+    pub fn map_assign<F>(&mut self, mut f: F)
+    where F: FnMut(T) -> T {
+        for value in self.iter_mut() {
+            *value = f(*value);
+        }
+    }
+}
+
+impl<'a, const X: usize, const Y: usize, T: Number> Index<usize> for ColumnMut<'a, X, Y, T>
+where [T; X * Y]: Sized {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, y: usize) -> &Self::Output {
+        self.get(y).unwrap()
+    }
+}
+
+impl<'a, const X: usize, const Y: usize, T: Number> IndexMut<usize> for ColumnMut<'a, X, Y, T>
+where [T; X * Y]: Sized {
+    #[inline]
+    fn index_mut(&mut self, y: usize) -> &mut Self::Output {
+        self.get_mut(y).unwrap()
+    }
+}
@@ -0,0 +1,356 @@
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::fmt::Display;
+
+use crate::{Matrix, Vector};
+use crate::number::{Number, FromUSize};
+use crate::ops::{Pow, PowAssign};
+
+// ======== Dual ===============================================================
+
+/// Dual number `value + deriv·ε` with `ε² = 0`, used for forward-mode
+/// automatic differentiation: evaluating `f(x + ε)` yields `f(x) + f'(x)·ε`.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Dual<T: Number=f64> {
+    pub value: T,
+    pub deriv: T,
+}
+
+impl<T: Number> Dual<T> {
+    #[inline]
+    pub const fn new(value: T, deriv: T) -> Self {
+        Self { value, deriv }
+    }
+
+    #[inline]
+    pub const fn constant(value: T) -> Self {
+        Self { value, deriv: T::ZERO }
+    }
+
+    /// The independent variable, i.e. with a derivative of one.
+    #[inline]
+    pub const fn variable(value: T) -> Self {
+        Self { value, deriv: T::ONE }
+    }
+
+    /// Applies the chain rule for a function with value `value` and
+    /// derivative `deriv` at `self.value`.
+    #[inline]
+    pub fn chain(self, value: T, deriv: T) -> Self {
+        Self { value, deriv: self.deriv * deriv }
+    }
+}
+
+macro_rules! impl_dual_float {
+    ($type:ident) => {
+        impl Dual<$type> {
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                let value = self.value.sqrt();
+                self.chain(value, 0.5 / value)
+            }
+
+            #[inline]
+            pub fn cbrt(self) -> Self {
+                let value = self.value.cbrt();
+                self.chain(value, 1.0 / (3.0 * value * value))
+            }
+
+            #[inline]
+            pub fn exp(self) -> Self {
+                let value = self.value.exp();
+                self.chain(value, value)
+            }
+
+            #[inline]
+            pub fn ln(self) -> Self {
+                self.chain(self.value.ln(), self.value.recip())
+            }
+
+            #[inline]
+            pub fn log2(self) -> Self {
+                self.chain(self.value.log2(), (self.value * std::$type::consts::LN_2).recip())
+            }
+
+            #[inline]
+            pub fn log10(self) -> Self {
+                self.chain(self.value.log10(), (self.value * std::$type::consts::LN_10).recip())
+            }
+
+            #[inline]
+            pub fn sin(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(sin, cos)
+            }
+
+            #[inline]
+            pub fn cos(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(cos, -sin)
+            }
+
+            #[inline]
+            pub fn tan(self) -> Self {
+                let value = self.value.tan();
+                self.chain(value, 1.0 + value * value)
+            }
+
+            #[inline]
+            pub fn asin(self) -> Self {
+                self.chain(self.value.asin(), (1.0 - self.value * self.value).sqrt().recip())
+            }
+
+            #[inline]
+            pub fn acos(self) -> Self {
+                self.chain(self.value.acos(), -(1.0 - self.value * self.value).sqrt().recip())
+            }
+
+            #[inline]
+            pub fn atan(self) -> Self {
+                self.chain(self.value.atan(), (1.0 + self.value * self.value).recip())
+            }
+
+            #[inline]
+            pub fn atan2(self, other: Self) -> Self {
+                let denom = self.value * self.value + other.value * other.value;
+                Self {
+                    value: self.value.atan2(other.value),
+                    deriv: (other.value * self.deriv - self.value * other.deriv) / denom,
+                }
+            }
+
+            #[inline]
+            pub fn sinh(self) -> Self {
+                self.chain(self.value.sinh(), self.value.cosh())
+            }
+
+            #[inline]
+            pub fn cosh(self) -> Self {
+                self.chain(self.value.cosh(), self.value.sinh())
+            }
+
+            #[inline]
+            pub fn tanh(self) -> Self {
+                let value = self.value.tanh();
+                self.chain(value, 1.0 - value * value)
+            }
+
+            #[inline]
+            pub fn abs(self) -> Self {
+                self.chain(self.value.abs(), self.value.signum())
+            }
+
+            #[inline]
+            pub fn recip(self) -> Self {
+                let value = self.value.recip();
+                self.chain(value, -value * value)
+            }
+
+            #[inline]
+            pub fn powi(self, n: i32) -> Self {
+                self.chain(self.value.powi(n), n as $type * self.value.powi(n - 1))
+            }
+
+            #[inline]
+            pub fn powf(self, n: $type) -> Self {
+                self.chain(self.value.powf(n), n * self.value.powf(n - 1.0))
+            }
+
+            /// `self^other` with both base and exponent being dual numbers.
+            #[inline]
+            pub fn powd(self, other: Self) -> Self {
+                let value = self.value.powf(other.value);
+                Self {
+                    value,
+                    deriv: value * (other.deriv * self.value.ln() + other.value * self.deriv / self.value),
+                }
+            }
+        }
+
+        impl Pow<i32> for Dual<$type> {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, rhs: i32) -> Self::Output {
+                self.powi(rhs)
+            }
+        }
+
+        impl Pow<$type> for Dual<$type> {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, rhs: $type) -> Self::Output {
+                self.powf(rhs)
+            }
+        }
+
+        impl PowAssign<i32> for Dual<$type> {
+            #[inline]
+            fn pow_assign(&mut self, rhs: i32) {
+                *self = self.powi(rhs)
+            }
+        }
+
+        impl PowAssign<$type> for Dual<$type> {
+            #[inline]
+            fn pow_assign(&mut self, rhs: $type) {
+                *self = self.powf(rhs)
+            }
+        }
+    };
+}
+
+impl_dual_float!(f32);
+impl_dual_float!(f64);
+
+impl<T: Number> Number for Dual<T> {
+    const ZERO: Self = Dual { value: T::ZERO, deriv: T::ZERO };
+    const ONE: Self = Dual { value: T::ONE, deriv: T::ZERO };
+}
+
+impl<T: Number + FromUSize> FromUSize for Dual<T> {
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        Self::constant(T::from_usize(value))
+    }
+}
+
+impl<T: Number> From<T> for Dual<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<T: Number> Display for Dual<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)?;
+        if self.deriv.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Less) {
+            f.write_str("+")?;
+        }
+        Display::fmt(&self.deriv, f)?;
+        f.write_str("ε")
+    }
+}
+
+impl<T: Number> Add for Dual<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { value: self.value + rhs.value, deriv: self.deriv + rhs.deriv }
+    }
+}
+
+impl<T: Number> Sub for Dual<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { value: self.value - rhs.value, deriv: self.deriv - rhs.deriv }
+    }
+}
+
+impl<T: Number> Mul for Dual<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value * rhs.value,
+            deriv: self.deriv * rhs.value + self.value * rhs.deriv,
+        }
+    }
+}
+
+impl<T: Number> Div for Dual<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value / rhs.value,
+            deriv: (self.deriv * rhs.value - self.value * rhs.deriv) / (rhs.value * rhs.value),
+        }
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for Dual<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { value: -self.value, deriv: -self.deriv }
+    }
+}
+
+impl<T: Number> AddAssign for Dual<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Number> SubAssign for Dual<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Number> MulAssign for Dual<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Number> DivAssign for Dual<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+// ======== Differentiation ====================================================
+
+/// Derivative of `f` at `at`.
+#[inline]
+pub fn derivative<T: Number, F>(f: F, at: T) -> T
+where F: Fn(Dual<T>) -> Dual<T> {
+    f(Dual::variable(at)).deriv
+}
+
+/// Gradient of the scalar function `f` at `at`. Needs one evaluation of `f`
+/// per input.
+pub fn gradient<const N: usize, T: Number, F>(f: F, at: &Vector<N, T>) -> Vector<N, T>
+where F: Fn(&Vector<N, Dual<T>>) -> Dual<T> {
+    let mut input = at.map(Dual::constant);
+    let mut index = 0;
+
+    Vector::from(Box::new([(); N].map(|_| {
+        input[index].deriv = T::ONE;
+        let deriv = f(&input).deriv;
+        input[index].deriv = T::ZERO;
+        index += 1;
+        deriv
+    })))
+}
+
+/// Jacobian of `f` at `at`: element `(x, y)` is the derivative of output `y`
+/// with respect to input `x`. Needs one evaluation of `f` per input.
+pub fn jacobian<const N: usize, const M: usize, T: Number, F>(f: F, at: &Vector<N, T>) -> Matrix<N, M, T>
+where F: Fn(&Vector<N, Dual<T>>) -> Vector<M, Dual<T>>, [T; N * M]: Sized {
+    let mut input = at.map(Dual::constant);
+    let mut data = Box::new([T::default(); N * M]);
+
+    for x in 0..N {
+        input[x].deriv = T::ONE;
+        for (y, value) in f(&input).iter().enumerate() {
+            data[y * N + x] = value.deriv;
+        }
+        input[x].deriv = T::ZERO;
+    }
+
+    Matrix::from(data)
+}
@@ -0,0 +1,243 @@
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::fmt::Display;
+
+use crate::number::{Number, FromUSize, Abs};
+use crate::ops::{Pow, PowAssign};
+
+// ======== Fixed ==============================================================
+
+/// Binary fixed-point number with `FRAC_BITS` fractional bits stored in the
+/// integer type `I` (`i8`, `i16`, `i32` or `i64`).
+///
+/// All operations are deterministic and platform independent:
+///
+/// * Every operation saturates at `MIN`/`MAX` instead of wrapping.
+/// * `*` and `/` are computed in the next wider integer type and round to
+///   nearest, ties away from zero.
+/// * Division by zero panics, just like for integers.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Fixed<I, const FRAC_BITS: u32> {
+    bits: I
+}
+
+macro_rules! impl_fixed {
+    ($type:ident $wide:ident) => {
+        impl<const FRAC_BITS: u32> Fixed<$type, FRAC_BITS> {
+            pub const FRAC_BITS: u32 = FRAC_BITS;
+            pub const MIN: Self = Self::from_bits($type::MIN);
+            pub const MAX: Self = Self::from_bits($type::MAX);
+            /// Smallest positive value.
+            pub const EPSILON: Self = Self::from_bits(1);
+
+            #[inline]
+            pub const fn from_bits(bits: $type) -> Self {
+                Self { bits }
+            }
+
+            #[inline]
+            pub const fn to_bits(self) -> $type {
+                self.bits
+            }
+
+            #[inline]
+            const fn saturate(value: $wide) -> Self {
+                if value > $type::MAX as $wide {
+                    Self::MAX
+                } else if value < $type::MIN as $wide {
+                    Self::MIN
+                } else {
+                    Self::from_bits(value as $type)
+                }
+            }
+
+            /// Saturates if `value` is out of range.
+            #[inline]
+            pub const fn from_int(value: $type) -> Self {
+                Self::saturate((value as $wide) << FRAC_BITS)
+            }
+
+            /// Rounds towards negative infinity.
+            #[inline]
+            pub const fn to_int(self) -> $type {
+                self.bits >> FRAC_BITS
+            }
+
+            /// Rounds to nearest and saturates. `NaN` becomes zero.
+            #[inline]
+            pub fn from_f64(value: f64) -> Self {
+                let value = (value * (1u128 << FRAC_BITS) as f64).round();
+                Self::from_bits(value as $type)
+            }
+
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.bits as f64 / (1u128 << FRAC_BITS) as f64
+            }
+
+            #[inline]
+            pub const fn abs(self) -> Self {
+                Self::from_bits(self.bits.saturating_abs())
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Abs for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn abs(self) -> Self {
+                Fixed::<$type, FRAC_BITS>::abs(self)
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Number for Fixed<$type, FRAC_BITS> {
+            const ZERO: Self = Self::from_bits(0);
+            const ONE: Self = if FRAC_BITS < $type::BITS - 1 {
+                Self::from_bits(1 << FRAC_BITS)
+            } else {
+                panic!("FRAC_BITS leaves no room for the integer part")
+            };
+        }
+
+        impl<const FRAC_BITS: u32> FromUSize for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn from_usize(value: usize) -> Self {
+                match $wide::try_from(value) {
+                    Ok(value) => Self::saturate(value.saturating_mul(1 << FRAC_BITS)),
+                    Err(_) => Self::MAX,
+                }
+            }
+        }
+
+        impl<const FRAC_BITS: u32> From<$type> for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn from(value: $type) -> Self {
+                Self::from_int(value)
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Display for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Add for Fixed<$type, FRAC_BITS> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::from_bits(self.bits.saturating_add(rhs.bits))
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Sub for Fixed<$type, FRAC_BITS> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::from_bits(self.bits.saturating_sub(rhs.bits))
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Mul for Fixed<$type, FRAC_BITS> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self::Output {
+                let product = self.bits as $wide * rhs.bits as $wide;
+                if FRAC_BITS == 0 {
+                    return Self::saturate(product);
+                }
+                let half = 1 << (FRAC_BITS - 1);
+                let rounded = if product < 0 { product - half } else { product + half };
+                Self::saturate(rounded / (1 << FRAC_BITS))
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Div for Fixed<$type, FRAC_BITS> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self::Output {
+                let lhs = (self.bits as $wide) << FRAC_BITS;
+                let rhs = rhs.bits as $wide;
+                let quot = lhs / rhs;
+                let rem = lhs % rhs;
+                if rem.unsigned_abs() * 2 >= rhs.unsigned_abs() {
+                    Self::saturate(if (lhs < 0) == (rhs < 0) { quot + 1 } else { quot - 1 })
+                } else {
+                    Self::saturate(quot)
+                }
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Neg for Fixed<$type, FRAC_BITS> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::from_bits(self.bits.saturating_neg())
+            }
+        }
+
+        impl<const FRAC_BITS: u32> AddAssign for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const FRAC_BITS: u32> SubAssign for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const FRAC_BITS: u32> MulAssign for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const FRAC_BITS: u32> DivAssign for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<const FRAC_BITS: u32> Pow<u32> for Fixed<$type, FRAC_BITS> {
+            type Output = Self;
+
+            /// Exponentiation by squaring, rounding after every step.
+            fn pow(self, mut rhs: u32) -> Self::Output {
+                let mut base = self;
+                let mut result = Self::ONE;
+                while rhs > 0 {
+                    if rhs & 1 != 0 {
+                        result *= base;
+                    }
+                    rhs >>= 1;
+                    if rhs > 0 {
+                        base *= base;
+                    }
+                }
+                result
+            }
+        }
+
+        impl<const FRAC_BITS: u32> PowAssign<u32> for Fixed<$type, FRAC_BITS> {
+            #[inline]
+            fn pow_assign(&mut self, rhs: u32) {
+                *self = self.pow(rhs)
+            }
+        }
+    };
+}
+
+impl_fixed!(i8 i16);
+impl_fixed!(i16 i32);
+impl_fixed!(i32 i64);
+impl_fixed!(i64 i128);
@@ -0,0 +1,392 @@
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::fmt::{Display, Debug};
+use std::cmp::Ordering;
+use std::iter::{Sum, Product};
+
+use crate::number::{Number, FromUSize, Widen, Abs};
+use crate::ops::{Pow, PowAssign};
+
+/// Rounds an `f64` to nearest, ties to even, into a 16 bit float with the
+/// given number of exponent and mantissa bits. Going through `f32` instead
+/// would round twice.
+const fn round_f64(value: f64, exp_bits: u32, mant_bits: u32) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 48) & 0x8000) as u16;
+    let exp = ((x >> 52) & 0x7ff) as i32;
+    let mant = x & 0xf_ffff_ffff_ffff;
+    let max_exp = (1 << exp_bits) - 1;
+    let drop = 52 - mant_bits;
+
+    if exp == 0x7ff {
+        let nan = if mant != 0 { (1 << (mant_bits - 1)) | (mant >> drop) as u16 } else { 0 };
+        return sign | (max_exp << mant_bits) as u16 | nan;
+    }
+
+    let exp = exp - 1023 + (max_exp >> 1);
+    if exp >= max_exp {
+        return sign | (max_exp << mant_bits) as u16;
+    }
+
+    let (mant, shift, base) = if exp <= 0 {
+        // subnormal or zero, f64 subnormals are way too small anyway
+        let shift = drop as i32 + 1 - exp;
+        if x & 0x7ff0_0000_0000_0000 == 0 || shift > 53 {
+            return sign;
+        }
+        (mant | (1 << 52), shift as u32, 0)
+    } else {
+        (mant, drop, (exp as u64) << mant_bits)
+    };
+
+    // a carry into the exponent rounds up to the next power of two or to
+    // infinity, which is what we want
+    let half = 1 << (shift - 1);
+    let rem = mant & ((1 << shift) - 1);
+    let mut bits = base | (mant >> shift);
+    if rem > half || (rem == half && bits & 1 != 0) {
+        bits += 1;
+    }
+    sign | bits as u16
+}
+
+// ======== f16 ================================================================
+
+/// IEEE 754 binary16 storage type. Arithmetic is done in `f32` and rounded
+/// back to nearest, ties to even, which gives correctly rounded results
+/// because `f32` has more than twice the precision.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct f16 {
+    bits: u16
+}
+
+impl f16 {
+    pub const ZERO: Self = Self::from_bits(0x0000);
+    pub const ONE: Self = Self::from_bits(0x3c00);
+    pub const MIN: Self = Self::from_bits(0xfbff);
+    pub const MAX: Self = Self::from_bits(0x7bff);
+    pub const MIN_POSITIVE: Self = Self::from_bits(0x0400);
+    pub const EPSILON: Self = Self::from_bits(0x1400);
+    pub const INFINITY: Self = Self::from_bits(0x7c00);
+    pub const NEG_INFINITY: Self = Self::from_bits(0xfc00);
+    pub const NAN: Self = Self::from_bits(0x7e00);
+
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self { bits }
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    pub const fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xff) as i32;
+        let mant = x & 0x7f_ffff;
+
+        if exp == 0xff {
+            let nan = if mant != 0 { 0x0200 | (mant >> 13) as u16 } else { 0 };
+            return Self::from_bits(sign | 0x7c00 | nan);
+        }
+
+        let exp = exp - 127 + 15;
+        if exp >= 0x1f {
+            return Self::from_bits(sign | 0x7c00);
+        }
+
+        if exp <= 0 {
+            // subnormal or zero
+            if exp < -10 {
+                return Self::from_bits(sign);
+            }
+            let mant = mant | 0x80_0000;
+            let shift = (14 - exp) as u32;
+            let half = 1 << (shift - 1);
+            let rem = mant & ((1 << shift) - 1);
+            let mut bits = mant >> shift;
+            if rem > half || (rem == half && bits & 1 != 0) {
+                bits += 1;
+            }
+            return Self::from_bits(sign | bits as u16);
+        }
+
+        // a carry into the exponent rounds up to the next power of two or
+        // to infinity, which is what we want
+        let mut bits = ((exp as u32) << 10) | (mant >> 13);
+        let rem = mant & 0x1fff;
+        if rem > 0x1000 || (rem == 0x1000 && bits & 1 != 0) {
+            bits += 1;
+        }
+        Self::from_bits(sign | bits as u16)
+    }
+
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
+        Self::from_bits(round_f64(value, 5, 10))
+    }
+
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.bits & 0x8000) as u32) << 16;
+        let exp = ((self.bits >> 10) & 0x1f) as u32;
+        let mant = (self.bits & 0x3ff) as u32;
+
+        if exp == 0x1f {
+            f32::from_bits(sign | 0x7f80_0000 | (mant << 13))
+        } else if exp == 0 {
+            let value = mant as f32 * f32::from_bits(0x3380_0000); // 2^-24
+            if sign != 0 { -value } else { value }
+        } else {
+            f32::from_bits(sign | ((exp + 112) << 23) | (mant << 13))
+        }
+    }
+}
+
+// ======== bf16 ===============================================================
+
+/// bfloat16 storage type: the upper half of an `f32`. Arithmetic is done in
+/// `f32` and rounded back to nearest, ties to even.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct bf16 {
+    bits: u16
+}
+
+impl bf16 {
+    pub const ZERO: Self = Self::from_bits(0x0000);
+    pub const ONE: Self = Self::from_bits(0x3f80);
+    pub const MIN: Self = Self::from_bits(0xff7f);
+    pub const MAX: Self = Self::from_bits(0x7f7f);
+    pub const MIN_POSITIVE: Self = Self::from_bits(0x0080);
+    pub const EPSILON: Self = Self::from_bits(0x3c00);
+    pub const INFINITY: Self = Self::from_bits(0x7f80);
+    pub const NEG_INFINITY: Self = Self::from_bits(0xff80);
+    pub const NAN: Self = Self::from_bits(0x7fc0);
+
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self { bits }
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    #[inline]
+    pub const fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+        if value.is_nan() {
+            return Self::from_bits((x >> 16) as u16 | 0x40);
+        }
+        let rounding = 0x7fff + ((x >> 16) & 1);
+        Self::from_bits((x.wrapping_add(rounding) >> 16) as u16)
+    }
+
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
+        Self::from_bits(round_f64(value, 8, 7))
+    }
+
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.bits as u32) << 16)
+    }
+}
+
+// ======== Common =============================================================
+
+macro_rules! impl_half {
+    ($type:ident) => {
+        impl $type {
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.to_f32() as f64
+            }
+
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.to_f32().is_nan()
+            }
+
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self::from_bits(self.bits & 0x7fff)
+            }
+        }
+
+        impl Number for $type {
+            const ZERO: Self = $type::ZERO;
+            const ONE: Self = $type::ONE;
+        }
+
+        impl Abs for $type {
+            #[inline]
+            fn abs(self) -> Self {
+                $type::abs(self)
+            }
+        }
+
+        impl FromUSize for $type {
+            #[inline]
+            fn from_usize(value: usize) -> Self {
+                Self::from_f64(value as f64)
+            }
+        }
+
+        impl Widen for $type {
+            type Wide = f32;
+
+            #[inline]
+            fn widen(self) -> f32 {
+                self.to_f32()
+            }
+
+            #[inline]
+            fn narrow(value: f32) -> Self {
+                Self::from_f32(value)
+            }
+        }
+
+        impl From<$type> for f32 {
+            #[inline]
+            fn from(value: $type) -> Self {
+                value.to_f32()
+            }
+        }
+
+        impl From<$type> for f64 {
+            #[inline]
+            fn from(value: $type) -> Self {
+                value.to_f64()
+            }
+        }
+
+        impl PartialEq for $type {
+            /// IEEE semantics: `NaN != NaN` and `0 == -0`.
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $type {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl Display for $type {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl Debug for $type {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl_half!(@op $type Add AddAssign add add_assign);
+        impl_half!(@op $type Sub SubAssign sub sub_assign);
+        impl_half!(@op $type Mul MulAssign mul mul_assign);
+        impl_half!(@op $type Div DivAssign div div_assign);
+
+        impl Sum for $type {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, value| acc + value)
+            }
+        }
+
+        impl<'a> Sum<&'a $type> for $type {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, value| acc + *value)
+            }
+        }
+
+        impl Product for $type {
+            #[inline]
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |acc, value| acc * value)
+            }
+        }
+
+        impl<'a> Product<&'a $type> for $type {
+            #[inline]
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |acc, value| acc * *value)
+            }
+        }
+
+        impl Neg for $type {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::from_bits(self.bits ^ 0x8000)
+            }
+        }
+
+        impl Pow<i32> for $type {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, rhs: i32) -> Self::Output {
+                Self::from_f32(self.to_f32().powi(rhs))
+            }
+        }
+
+        impl Pow<$type> for $type {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, rhs: $type) -> Self::Output {
+                Self::from_f32(self.to_f32().powf(rhs.to_f32()))
+            }
+        }
+
+        impl PowAssign<i32> for $type {
+            #[inline]
+            fn pow_assign(&mut self, rhs: i32) {
+                *self = self.pow(rhs)
+            }
+        }
+
+        impl PowAssign<$type> for $type {
+            #[inline]
+            fn pow_assign(&mut self, rhs: $type) {
+                *self = self.pow(rhs)
+            }
+        }
+    };
+    (@op $type:ident $trait:ident $trait_assign:ident $op:ident $op_assign:ident) => {
+        impl $trait for $type {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: Self) -> Self::Output {
+                Self::from_f32(self.to_f32().$op(rhs.to_f32()))
+            }
+        }
+
+        impl $trait_assign for $type {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = (*self).$op(rhs);
+            }
+        }
+    };
+}
+
+impl_half!(f16);
+impl_half!(bf16);
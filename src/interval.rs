@@ -0,0 +1,377 @@
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::fmt::Display;
+use std::cmp::Ordering;
+
+use crate::number::{Number, FromUSize};
+use crate::ops::{Pow, PowAssign};
+
+// ======== Interval ===========================================================
+
+/// Closed interval `[lo, hi]` of `f32` or `f64` values.
+///
+/// Every operation rounds outward, so the result always encloses the exact
+/// result for all values of the operands. Rounding errors are detected with
+/// error-free transformations (TwoSum and FMA), which keeps exactly
+/// representable results tight instead of blindly widening by one ulp. Close
+/// to the subnormal range that doesn't work and results are always widened.
+///
+/// The order is partial: `a < b` only if `a.hi() < b.lo()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Interval<T=f64> {
+    lo: T,
+    hi: T,
+}
+
+impl<T: Copy + PartialOrd + Display> Interval<T> {
+    /// Panics if `lo > hi` or either is `NaN`.
+    #[inline]
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(lo <= hi, "invalid interval [{}, {}]", lo, hi);
+        Self { lo, hi }
+    }
+
+    #[inline]
+    pub const fn point(value: T) -> Self {
+        Self { lo: value, hi: value }
+    }
+
+    #[inline]
+    pub const fn lo(&self) -> T {
+        self.lo
+    }
+
+    #[inline]
+    pub const fn hi(&self) -> T {
+        self.hi
+    }
+}
+
+macro_rules! impl_interval {
+    ($type:ident) => {
+        impl Interval<$type> {
+            pub const ENTIRE: Self = Self { lo: $type::NEG_INFINITY, hi: $type::INFINITY };
+
+            /// Below this the FMA residuals used for rounding may underflow,
+            /// so results are widened by one ulp in both directions instead.
+            const TINY: $type = $type::MIN_POSITIVE / ($type::EPSILON * $type::EPSILON);
+
+            /// Upper bound of `hi - lo`.
+            #[inline]
+            pub fn width(&self) -> $type {
+                Self::sub_bounds(self.hi, self.lo).1
+            }
+
+            #[inline]
+            pub fn midpoint(&self) -> $type {
+                self.lo / 2.0 + self.hi / 2.0
+            }
+
+            #[inline]
+            pub fn contains(&self, value: $type) -> bool {
+                self.lo <= value && value <= self.hi
+            }
+
+            #[inline]
+            pub fn contains_interval(&self, other: &Self) -> bool {
+                self.lo <= other.lo && other.hi <= self.hi
+            }
+
+            /// Smallest interval containing both.
+            #[inline]
+            pub fn hull(&self, other: &Self) -> Self {
+                Self { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+            }
+
+            #[inline]
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let lo = self.lo.max(other.lo);
+                let hi = self.hi.min(other.hi);
+                if lo <= hi { Some(Self { lo, hi }) } else { None }
+            }
+
+            #[inline]
+            pub fn abs(self) -> Self {
+                if self.lo >= 0.0 {
+                    self
+                } else if self.hi <= 0.0 {
+                    -self
+                } else {
+                    Self { lo: 0.0, hi: self.hi.max(-self.lo) }
+                }
+            }
+
+            /// Negative parts are cut off.
+            pub fn sqrt(self) -> Self {
+                let sqrt = |value: $type| {
+                    let root = value.sqrt();
+                    if value > 0.0 && value < Self::TINY {
+                        return Self::round(root, $type::NAN);
+                    }
+                    // residual = value - root²
+                    let residual = -root.mul_add(root, -value);
+                    Self::round(root, residual)
+                };
+                Self { lo: sqrt(self.lo.max(0.0)).0, hi: sqrt(self.hi.max(0.0)).1 }
+            }
+
+            /// Encloses `value + err`, where `err` only matters by its sign.
+            /// A `NaN` error widens in both directions.
+            #[inline]
+            fn round(value: $type, err: $type) -> ($type, $type) {
+                if err == 0.0 {
+                    (value, value)
+                } else if err < 0.0 {
+                    (value.next_down(), value)
+                } else if err > 0.0 {
+                    (value, value.next_up())
+                } else {
+                    (value.next_down(), value.next_up())
+                }
+            }
+
+            #[inline]
+            fn add_bounds(lhs: $type, rhs: $type) -> ($type, $type) {
+                let sum = lhs + rhs;
+                let rhs_part = sum - lhs;
+                let err = (lhs - (sum - rhs_part)) + (rhs - rhs_part);
+                Self::round(sum, err)
+            }
+
+            #[inline]
+            fn sub_bounds(lhs: $type, rhs: $type) -> ($type, $type) {
+                Self::add_bounds(lhs, -rhs)
+            }
+
+            #[inline]
+            fn mul_bounds(lhs: $type, rhs: $type) -> ($type, $type) {
+                let product = lhs * rhs;
+                if product.abs() < Self::TINY && lhs != 0.0 && rhs != 0.0 {
+                    return Self::round(product, $type::NAN);
+                }
+                Self::round(product, lhs.mul_add(rhs, -product))
+            }
+
+            #[inline]
+            fn div_bounds(lhs: $type, rhs: $type) -> ($type, $type) {
+                let quot = lhs / rhs;
+                if rhs.is_infinite() && lhs.is_finite() {
+                    return (quot, quot);
+                }
+                if (quot.abs() < Self::TINY || lhs.abs() < Self::TINY) && lhs != 0.0 {
+                    return Self::round(quot, $type::NAN);
+                }
+                // residual = lhs - quot * rhs, the error has the sign of residual / rhs
+                let residual = -quot.mul_add(rhs, -lhs);
+                Self::round(quot, if rhs < 0.0 { -residual } else { residual })
+            }
+
+            /// Even powers of intervals containing zero start at zero instead
+            /// of going negative like repeated multiplication would.
+            pub fn powu(self, exp: u32) -> Self {
+                if exp == 0 {
+                    return Self::ONE;
+                }
+
+                if exp & 1 != 0 {
+                    let lo = if self.lo < 0.0 { -Self::pow_bounds(-self.lo, exp).1 } else { Self::pow_bounds(self.lo, exp).0 };
+                    let hi = if self.hi < 0.0 { -Self::pow_bounds(-self.hi, exp).0 } else { Self::pow_bounds(self.hi, exp).1 };
+                    Self { lo, hi }
+                } else {
+                    let abs = self.abs();
+                    Self { lo: Self::pow_bounds(abs.lo, exp).0, hi: Self::pow_bounds(abs.hi, exp).1 }
+                }
+            }
+
+            /// Lower and upper bound of `base^exp` for `base >= 0`.
+            fn pow_bounds(base: $type, mut exp: u32) -> ($type, $type) {
+                let mut lo_base = base;
+                let mut hi_base = base;
+                let mut lo = 1.0;
+                let mut hi = 1.0;
+                while exp > 0 {
+                    if exp & 1 != 0 {
+                        lo = Self::mul_bounds(lo, lo_base).0;
+                        hi = Self::mul_bounds(hi, hi_base).1;
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        lo_base = Self::mul_bounds(lo_base, lo_base).0;
+                        hi_base = Self::mul_bounds(hi_base, hi_base).1;
+                    }
+                }
+                (lo, hi)
+            }
+        }
+
+        impl Number for Interval<$type> {
+            const ZERO: Self = Interval { lo: 0.0, hi: 0.0 };
+            const ONE: Self = Interval { lo: 1.0, hi: 1.0 };
+        }
+
+        impl FromUSize for Interval<$type> {
+            #[inline]
+            fn from_usize(value: usize) -> Self {
+                let approx = value as $type;
+                match (approx as u128).cmp(&(value as u128)) {
+                    Ordering::Equal   => Self::point(approx),
+                    Ordering::Less    => Self { lo: approx, hi: approx.next_up() },
+                    Ordering::Greater => Self { lo: approx.next_down(), hi: approx },
+                }
+            }
+        }
+
+        impl From<$type> for Interval<$type> {
+            #[inline]
+            fn from(value: $type) -> Self {
+                Self::point(value)
+            }
+        }
+
+        impl PartialOrd for Interval<$type> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                if self == other {
+                    Some(Ordering::Equal)
+                } else if self.hi < other.lo {
+                    Some(Ordering::Less)
+                } else if self.lo > other.hi {
+                    Some(Ordering::Greater)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl Display for Interval<$type> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("[")?;
+                Display::fmt(&self.lo, f)?;
+                f.write_str(", ")?;
+                Display::fmt(&self.hi, f)?;
+                f.write_str("]")
+            }
+        }
+
+        impl Add for Interval<$type> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    lo: Self::add_bounds(self.lo, rhs.lo).0,
+                    hi: Self::add_bounds(self.hi, rhs.hi).1,
+                }
+            }
+        }
+
+        impl Sub for Interval<$type> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    lo: Self::sub_bounds(self.lo, rhs.hi).0,
+                    hi: Self::sub_bounds(self.hi, rhs.lo).1,
+                }
+            }
+        }
+
+        impl Mul for Interval<$type> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                let products = [
+                    Self::mul_bounds(self.lo, rhs.lo),
+                    Self::mul_bounds(self.lo, rhs.hi),
+                    Self::mul_bounds(self.hi, rhs.lo),
+                    Self::mul_bounds(self.hi, rhs.hi),
+                ];
+                Self {
+                    lo: products.iter().fold($type::INFINITY, |acc, (lo, _)| acc.min(*lo)),
+                    hi: products.iter().fold($type::NEG_INFINITY, |acc, (_, hi)| acc.max(*hi)),
+                }
+            }
+        }
+
+        impl Div for Interval<$type> {
+            type Output = Self;
+
+            /// Division by an interval containing zero yields `ENTIRE`.
+            fn div(self, rhs: Self) -> Self::Output {
+                if rhs.contains(0.0) {
+                    return Self::ENTIRE;
+                }
+                let quots = [
+                    Self::div_bounds(self.lo, rhs.lo),
+                    Self::div_bounds(self.lo, rhs.hi),
+                    Self::div_bounds(self.hi, rhs.lo),
+                    Self::div_bounds(self.hi, rhs.hi),
+                ];
+                Self {
+                    lo: quots.iter().fold($type::INFINITY, |acc, (lo, _)| acc.min(*lo)),
+                    hi: quots.iter().fold($type::NEG_INFINITY, |acc, (_, hi)| acc.max(*hi)),
+                }
+            }
+        }
+
+        impl Neg for Interval<$type> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self { lo: -self.hi, hi: -self.lo }
+            }
+        }
+
+        impl AddAssign for Interval<$type> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for Interval<$type> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for Interval<$type> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for Interval<$type> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Pow<i32> for Interval<$type> {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, rhs: i32) -> Self::Output {
+                if rhs < 0 {
+                    Self::ONE / self.powu(rhs.unsigned_abs())
+                } else {
+                    self.powu(rhs as u32)
+                }
+            }
+        }
+
+        impl PowAssign<i32> for Interval<$type> {
+            #[inline]
+            fn pow_assign(&mut self, rhs: i32) {
+                *self = self.pow(rhs)
+            }
+        }
+    };
+}
+
+impl_interval!(f32);
+impl_interval!(f64);
@@ -6,11 +6,46 @@ mod number;
 mod vector;
 mod matrix;
 mod assert;
+mod banded;
+mod packed;
+mod tensor;
+mod permutation;
+mod mask;
+mod ratio;
+mod fixed;
+mod dual;
+mod interval;
+mod modp;
+mod bitmatrix;
+mod half;
+mod overflow;
+mod cast;
+mod semiring;
+mod approx;
+mod stats;
 pub mod ops;
 pub mod range;
 pub mod bycolumn;
 pub mod iter;
+pub mod column;
 
 pub use number::*;
 pub use vector::*;
 pub use matrix::*;
+pub use banded::*;
+pub use packed::*;
+pub use tensor::*;
+pub use permutation::*;
+pub use mask::*;
+pub use ratio::*;
+pub use fixed::*;
+pub use dual::*;
+pub use interval::*;
+pub use modp::*;
+pub use bitmatrix::*;
+pub use half::*;
+pub use overflow::*;
+pub use cast::*;
+pub use semiring::*;
+pub use approx::*;
+pub use stats::{TotalOrd, Interpolation};
@@ -0,0 +1,414 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not, Index};
+
+use crate::{Matrix, Vector};
+use crate::number::Number;
+
+// ======== MaskOperand ========================================================
+
+/// Right hand side of element-wise comparisons, `select()` and the checked,
+/// wrapping and saturating arithmetic: either a matrix/vector of the same
+/// shape or a scalar that is broadcast.
+pub trait MaskOperand<const X: usize, const Y: usize, T: Number> {
+    fn element(&self, index: usize) -> T;
+}
+
+impl<const X: usize, const Y: usize, T: Number> MaskOperand<X, Y, T> for T {
+    #[inline]
+    fn element(&self, _index: usize) -> T {
+        *self
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Number> MaskOperand<X, Y, T> for &Matrix<X, Y, T>
+where [T; X * Y]: Sized {
+    #[inline]
+    fn element(&self, index: usize) -> T {
+        self.data()[index]
+    }
+}
+
+impl<const N: usize, T: Number> MaskOperand<N, 1, T> for &Vector<N, T> {
+    #[inline]
+    fn element(&self, index: usize) -> T {
+        self.data()[index]
+    }
+}
+
+// ======== Mask ===============================================================
+
+/// Boolean matrix as returned by element-wise comparisons. Vectors produce
+/// `Mask<N, 1>`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mask<const X: usize, const Y: usize>
+where [bool; X * Y]: Sized
+{
+    data: Box<[bool; X * Y]>
+}
+
+impl<const X: usize, const Y: usize> Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    pub const X: usize = X;
+    pub const Y: usize = Y;
+    pub const SHAPE: [usize; 2] = [Y, X];
+
+    #[inline]
+    pub fn new(value: bool) -> Self {
+        Self { data: Box::new([value; X * Y]) }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl std::iter::Iterator<Item = &bool> {
+        self.data.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl std::iter::Iterator<Item = &mut bool> {
+        self.data.iter_mut()
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[bool; X * Y] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [bool; X * Y] {
+        &mut self.data
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if x >= X {
+            return None;
+        }
+        self.data.get(y * X + x).copied()
+    }
+
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(x < X && y < Y, "index out of bounds");
+        self.data[y * X + x] = value;
+    }
+
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.data.iter().any(|&value| value)
+    }
+
+    #[inline]
+    pub fn all(&self) -> bool {
+        self.data.iter().all(|&value| value)
+    }
+
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.data.iter().filter(|&&value| value).count()
+    }
+
+    /// `ONE` where the mask is set and `ZERO` elsewhere.
+    #[inline]
+    pub fn to_matrix<T: Number>(&self) -> Matrix<X, Y, T>
+    where [T; X * Y]: Sized {
+        Matrix::from(Box::new(self.data.map(|value| if value { T::ONE } else { T::ZERO })))
+    }
+}
+
+impl<const X: usize, const Y: usize> Default for Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<const X: usize, const Y: usize> From<[[bool; X]; Y]> for Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    #[inline]
+    fn from(value: [[bool; X]; Y]) -> Self {
+        let mut data = Box::new([false; X * Y]);
+        for (dest, row) in data.chunks_exact_mut(X).zip(value.iter()) {
+            dest.copy_from_slice(row);
+        }
+        Self { data }
+    }
+}
+
+// XXX: the compiler doesn't understand that N * 1 == N
+#[allow(clippy::identity_op)]
+impl<const N: usize> From<[bool; N]> for Mask<N, 1>
+where [bool; N * 1]: Sized
+{
+    #[inline]
+    fn from(value: [bool; N]) -> Self {
+        let mut mask = Self::new(false);
+        mask.data.copy_from_slice(&value);
+        mask
+    }
+}
+
+impl<const X: usize, const Y: usize> PartialEq<[[bool; X]; Y]> for Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    #[inline]
+    fn eq(&self, other: &[[bool; X]; Y]) -> bool {
+        self.data.chunks_exact(X).zip(other.iter()).all(|(lhs, rhs)| lhs == rhs)
+    }
+}
+
+impl<const X: usize, const Y: usize> Index<(usize, usize)> for Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    type Output = bool;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < X, "index out of bounds");
+        &self.data[y * X + x]
+    }
+}
+
+impl<const X: usize, const Y: usize> std::iter::IntoIterator for Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    type Item = bool;
+    type IntoIter = std::array::IntoIter<bool, { X * Y }>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+macro_rules! impl_bit_ops {
+    ($trait:ident $op:ident) => {
+        impl<const X: usize, const Y: usize> $trait<&Mask<X, Y>> for &Mask<X, Y>
+        where [bool; X * Y]: Sized
+        {
+            type Output = Mask<X, Y>;
+
+            #[inline]
+            fn $op(self, rhs: &Mask<X, Y>) -> Self::Output {
+                let mut index = 0;
+                let data = Box::new(self.data.map(|value| {
+                    let value = value.$op(rhs.data[index]);
+                    index += 1;
+                    value
+                }));
+                Mask { data }
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait<Mask<X, Y>> for &Mask<X, Y>
+        where [bool; X * Y]: Sized
+        {
+            type Output = Mask<X, Y>;
+
+            #[inline]
+            fn $op(self, rhs: Mask<X, Y>) -> Self::Output {
+                self.$op(&rhs)
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait<&Mask<X, Y>> for Mask<X, Y>
+        where [bool; X * Y]: Sized
+        {
+            type Output = Mask<X, Y>;
+
+            #[inline]
+            fn $op(mut self, rhs: &Mask<X, Y>) -> Self::Output {
+                for (lhs, rhs) in self.data.iter_mut().zip(rhs.data.iter()) {
+                    *lhs = lhs.$op(*rhs);
+                }
+                self
+            }
+        }
+
+        impl<const X: usize, const Y: usize> $trait<Mask<X, Y>> for Mask<X, Y>
+        where [bool; X * Y]: Sized
+        {
+            type Output = Mask<X, Y>;
+
+            #[inline]
+            fn $op(self, rhs: Mask<X, Y>) -> Self::Output {
+                self.$op(&rhs)
+            }
+        }
+    };
+}
+
+impl_bit_ops!(BitAnd bitand);
+impl_bit_ops!(BitOr bitor);
+impl_bit_ops!(BitXor bitxor);
+
+impl<const X: usize, const Y: usize> Not for Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    type Output = Mask<X, Y>;
+
+    #[inline]
+    fn not(mut self) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = !*value;
+        }
+        self
+    }
+}
+
+impl<const X: usize, const Y: usize> Not for &Mask<X, Y>
+where [bool; X * Y]: Sized
+{
+    type Output = Mask<X, Y>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        Mask { data: Box::new(self.data.map(|value| !value)) }
+    }
+}
+
+// ======== Comparisons ========================================================
+
+macro_rules! impl_compare {
+    ($($name:ident $op:tt),* $(,)?) => {
+        impl<const X: usize, const Y: usize, T: Number> Matrix<X, Y, T>
+        where [T; X * Y]: Sized, [bool; X * Y]: Sized
+        {
+            $(
+                #[inline]
+                pub fn $name<Rhs: MaskOperand<X, Y, T>>(&self, rhs: Rhs) -> Mask<X, Y> {
+                    self.compare(rhs, |lhs, rhs| lhs $op rhs)
+                }
+            )*
+        }
+
+        impl<const N: usize, T: Number> Vector<N, T>
+        where [bool; N * 1]: Sized
+        {
+            $(
+                #[inline]
+                pub fn $name<Rhs: MaskOperand<N, 1, T>>(&self, rhs: Rhs) -> Mask<N, 1> {
+                    self.compare(rhs, |lhs, rhs| lhs $op rhs)
+                }
+            )*
+        }
+    };
+}
+
+// These shadow the `PartialOrd` methods of the same name. Use the `<`, `<=`,
+// ... operators for the lexicographic comparison of the whole matrix.
+impl_compare!(
+    lt <,
+    le <=,
+    gt >,
+    ge >=,
+    eq_elem ==,
+    ne_elem !=,
+);
+
+impl<const X: usize, const Y: usize, T: Number> Matrix<X, Y, T>
+where [T; X * Y]: Sized, [bool; X * Y]: Sized
+{
+    /// Mask of the elements for which `f` returns `true`.
+    #[inline]
+    pub fn mask<F>(&self, f: F) -> Mask<X, Y>
+    where F: FnMut(T) -> bool {
+        Mask { data: Box::new(self.data().map(f)) }
+    }
+
+    #[inline]
+    pub fn compare<Rhs, F>(&self, rhs: Rhs, mut f: F) -> Mask<X, Y>
+    where Rhs: MaskOperand<X, Y, T>, F: FnMut(T, T) -> bool {
+        let mut index = 0;
+        Mask { data: Box::new(self.data().map(|value| {
+            let value = f(value, rhs.element(index));
+            index += 1;
+            value
+        })) }
+    }
+
+    /// Takes elements from `a` where `mask` is set and from `b` elsewhere
+    /// (numpy's `where`). Both may be scalars.
+    #[inline]
+    pub fn select<A, B>(mask: &Mask<X, Y>, a: A, b: B) -> Self
+    where A: MaskOperand<X, Y, T>, B: MaskOperand<X, Y, T> {
+        let mut index = 0;
+        Matrix::from(Box::new(mask.data.map(|value| {
+            let value = if value { a.element(index) } else { b.element(index) };
+            index += 1;
+            value
+        })))
+    }
+
+    #[inline]
+    pub fn masked_fill(&self, mask: &Mask<X, Y>, value: T) -> Self {
+        let mut matrix = self.clone();
+        matrix.masked_fill_assign(mask, value);
+        matrix
+    }
+
+    #[inline]
+    pub fn masked_fill_assign(&mut self, mask: &Mask<X, Y>, value: T) {
+        for (dest, &set) in self.iter_mut().zip(mask.data.iter()) {
+            if set {
+                *dest = value;
+            }
+        }
+    }
+}
+
+#[allow(clippy::identity_op)]
+impl<const N: usize, T: Number> Vector<N, T>
+where [bool; N * 1]: Sized
+{
+    /// Mask of the elements for which `f` returns `true`.
+    #[inline]
+    pub fn mask<F>(&self, mut f: F) -> Mask<N, 1>
+    where F: FnMut(T) -> bool {
+        let mut mask = Mask::new(false);
+        for (dest, &value) in mask.data.iter_mut().zip(self.iter()) {
+            *dest = f(value);
+        }
+        mask
+    }
+
+    #[inline]
+    pub fn compare<Rhs, F>(&self, rhs: Rhs, mut f: F) -> Mask<N, 1>
+    where Rhs: MaskOperand<N, 1, T>, F: FnMut(T, T) -> bool {
+        let mut mask = Mask::new(false);
+        for (index, (dest, &value)) in mask.data.iter_mut().zip(self.iter()).enumerate() {
+            *dest = f(value, rhs.element(index));
+        }
+        mask
+    }
+
+    /// Takes elements from `a` where `mask` is set and from `b` elsewhere
+    /// (numpy's `where`). Both may be scalars.
+    #[inline]
+    pub fn select<A, B>(mask: &Mask<N, 1>, a: A, b: B) -> Self
+    where A: MaskOperand<N, 1, T>, B: MaskOperand<N, 1, T> {
+        let mut index = 0;
+        Vector::from(Box::new([(); N].map(|_| {
+            let value = if mask.data[index] { a.element(index) } else { b.element(index) };
+            index += 1;
+            value
+        })))
+    }
+
+    #[inline]
+    pub fn masked_fill(&self, mask: &Mask<N, 1>, value: T) -> Self {
+        let mut vector = self.clone();
+        vector.masked_fill_assign(mask, value);
+        vector
+    }
+
+    #[inline]
+    pub fn masked_fill_assign(&mut self, mask: &Mask<N, 1>, value: T) {
+        for (dest, &set) in self.iter_mut().zip(mask.data.iter()) {
+            if set {
+                *dest = value;
+            }
+        }
+    }
+}
@@ -4,11 +4,12 @@ use std::iter::{Sum, Product, IntoIterator};
 use std::fmt::{Display, Debug};
 
 use crate::iter::{RowIter, ColumnIter};
-use crate::{Vector, FromUSize};
+use crate::{Vector, FromUSize, TryFromUSize, TryAvgError, Complex, Real, Widen, Abs};
 use crate::assert::{Assert, IsTrue};
 use crate::bycolumn::{ByColumn, ByColumnMut, IntoByColumn};
+use crate::column::ColumnMut;
 use crate::number::Number;
-use crate::ops::{Get, GetMut, Pow, PowAssign, Unit, Dot, DotAssign, Slice, Tap, Pipe, MatrixAggregate};
+use crate::ops::{Get, GetMut, Pow, PowAssign, Unit, Dot, DotAssign, Slice, SliceAssign, Tap, Pipe, MatrixAggregate};
 use crate::range::{RangeIter, Range};
 
 #[repr(transparent)]
@@ -67,6 +68,56 @@ where [T; X * Y]: Sized
         self.try_row(y).unwrap()
     }
 
+    #[inline]
+    pub fn try_row_mut(&mut self, y: usize) -> Option<&mut [T; X]> {
+        unsafe { self.data.as_chunks_unchecked_mut::<X>() }.get_mut(y)
+    }
+
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> &mut [T; X] {
+        self.try_row_mut(y).unwrap()
+    }
+
+    #[inline]
+    pub fn set_row(&mut self, y: usize, row: &Vector<X, T>) {
+        self.row_mut(y).copy_from_slice(row.data());
+    }
+
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) {
+        assert!(y1 < Y && y2 < Y, "row index out of bounds");
+        if y1 == y2 {
+            return;
+        }
+        let (lo, hi) = if y1 < y2 { (y1, y2) } else { (y2, y1) };
+        let (head, tail) = self.data.split_at_mut(hi * X);
+        head[lo * X..(lo + 1) * X].swap_with_slice(&mut tail[..X]);
+    }
+
+    /// Reorders the rows so that row `y` of the result is row `permutation[y]`
+    /// of `self`. Panics if `permutation` isn't a permutation of `0..Y`.
+    pub fn permute_rows(&mut self, permutation: &[usize; Y]) {
+        let mut visited = [false; Y];
+
+        for start in 0..Y {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+
+            let mut y = start;
+            loop {
+                let src = permutation[y];
+                if src == start {
+                    break;
+                }
+                assert!(src < Y && !visited[src], "not a permutation: {:?}", permutation);
+                visited[src] = true;
+                self.swap_rows(y, src);
+                y = src;
+            }
+        }
+    }
+
     #[inline]
     pub fn rows(&self) -> RowIter<'_, X, Y, T> {
         RowIter::new(self)
@@ -91,6 +142,28 @@ where [T; X * Y]: Sized
         self.try_column(x).unwrap()
     }
 
+    #[inline]
+    pub fn try_column_mut(&mut self, x: usize) -> Option<ColumnMut<'_, X, Y, T>> {
+        ColumnMut::new(self, x)
+    }
+
+    #[inline]
+    pub fn column_mut(&mut self, x: usize) -> ColumnMut<'_, X, Y, T> {
+        self.try_column_mut(x).unwrap()
+    }
+
+    #[inline]
+    pub fn set_column(&mut self, x: usize, column: &Vector<Y, T>) {
+        self.column_mut(x).assign(column);
+    }
+
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) {
+        assert!(x1 < X && x2 < X, "column index out of bounds");
+        for row in self.iter_arrays_mut() {
+            row.swap(x1, x2);
+        }
+    }
+
     #[inline]
     pub fn columns(&self) -> ColumnIter<'_, X, Y, T> {
         ColumnIter::new(self)
@@ -204,6 +277,7 @@ where [T; X * Y]: Sized
     }
 
     #[inline]
+    #[deprecated(note = "this computes the median, use `by_column().median()` instead")]
     pub fn mean_column(&self, x: usize) -> T
     where T: Ord {
         let mut data = self.column(x);
@@ -268,33 +342,201 @@ where [T; X * Y]: Sized
         }
     }
 
-    #[inline]
+    /// Transposes in place, reusing the allocation of `self`.
+    ///
+    /// Non-square matrices are transposed by following the cycles of the
+    /// index permutation, which only needs a bitset of `X * Y` bits to
+    /// remember which elements have already been moved.
     pub fn into_transpose(mut self) -> Matrix<Y, X, T>
-    where [T; Y * X]: Sized, Assert<{ X == Y }>: IsTrue {
-        self.transpose_assign();
-        // XXX: the compiler doesn't understand that X == Y
+    where [T; Y * X]: Sized {
+        if X == Y {
+            for y in 1..Y {
+                let yoffset = y * X;
+                for x in 0..y {
+                    self.data.swap(yoffset + x, x * X + y);
+                }
+            }
+        } else if X > 1 && Y > 1 {
+            // The element at index i moves to (i * Y) mod (X * Y - 1).
+            // The first and the last element stay where they are. Each cycle
+            // is rotated once, starting at its smallest index. Finding that
+            // needs no scratch memory, but walks each cycle more than once.
+            let len = X * Y - 1;
+
+            for start in 1..len {
+                let mut index = start * Y % len;
+                while index > start {
+                    index = index * Y % len;
+                }
+                if index < start {
+                    continue;
+                }
+
+                let mut index = start;
+                let mut value = self.data[start];
+                loop {
+                    index = index * Y % len;
+                    value = replace(&mut self.data[index], value);
+
+                    if index == start {
+                        break;
+                    }
+                }
+            }
+        }
+
+        // XXX: the compiler doesn't understand that X * Y == Y * X
         unsafe { std::mem::transmute(self) }
     }
 
-// XXX: Wrong and quadratic performance!
-//    #[inline]
-//    pub fn into_transpose(mut self) -> Matrix<Y, X, T>
-//    where [T; Y * X]: Sized {
-//        for y in 1..Y {
-//            let yoffset = y * X;
-//            for x in 0..X {
-//                let dest_index = yoffset + x;
-//                let mut src_index = x * Y + y;
-//                while src_index < dest_index {
-//                    let y2 = src_index / X;
-//                    let x2 = src_index - y2;
-//                    src_index = x2 * Y + y2;
-//                }
-//                self.data.swap(dest_index, src_index);
-//            }
-//        }
-//        unsafe { std::mem::transmute(self) }
-//    }
+    /// Places `rhs` to the right of `self`: `[self | rhs]`.
+    pub fn hstack<const X2: usize>(&self, rhs: &Matrix<X2, Y, T>) -> Matrix<{ X + X2 }, Y, T>
+    where [T; X2 * Y]: Sized, [T; (X + X2) * Y]: Sized {
+        let mut data = Box::new([T::default(); (X + X2) * Y]);
+
+        for y in 0..Y {
+            let yoffset = y * (X + X2);
+            data[yoffset..yoffset + X].copy_from_slice(&self.data[y * X..(y + 1) * X]);
+            data[yoffset + X..yoffset + X + X2].copy_from_slice(&rhs.data[y * X2..(y + 1) * X2]);
+        }
+
+        Matrix { data }
+    }
+
+    /// Places `rhs` below `self`.
+    pub fn vstack<const Y2: usize>(&self, rhs: &Matrix<X, Y2, T>) -> Matrix<X, { Y + Y2 }, T>
+    where [T; X * Y2]: Sized, [T; X * (Y + Y2)]: Sized {
+        let mut data = Box::new([T::default(); X * (Y + Y2)]);
+
+        data[..X * Y].copy_from_slice(&self.data[..]);
+        data[X * Y..].copy_from_slice(&rhs.data[..]);
+
+        Matrix { data }
+    }
+
+    /// Assembles the block matrix
+    /// ```text
+    /// [ a  b ]
+    /// [ c  d ]
+    /// ```
+    pub fn from_blocks<const X2: usize, const Y2: usize>(
+        a: &Self,
+        b: &Matrix<X2, Y, T>,
+        c: &Matrix<X, Y2, T>,
+        d: &Matrix<X2, Y2, T>
+    ) -> Matrix<{ X + X2 }, { Y + Y2 }, T>
+    where [T; X2 * Y]: Sized, [T; X * Y2]: Sized, [T; X2 * Y2]: Sized, [T; (X + X2) * (Y + Y2)]: Sized {
+        let mut data = Box::new([T::default(); (X + X2) * (Y + Y2)]);
+
+        for y in 0..Y {
+            let yoffset = y * (X + X2);
+            data[yoffset..yoffset + X].copy_from_slice(&a.data[y * X..(y + 1) * X]);
+            data[yoffset + X..yoffset + X + X2].copy_from_slice(&b.data[y * X2..(y + 1) * X2]);
+        }
+
+        for y in 0..Y2 {
+            let yoffset = (Y + y) * (X + X2);
+            data[yoffset..yoffset + X].copy_from_slice(&c.data[y * X..(y + 1) * X]);
+            data[yoffset + X..yoffset + X + X2].copy_from_slice(&d.data[y * X2..(y + 1) * X2]);
+        }
+
+        Matrix { data }
+    }
+
+    /// Splits into the first `K` columns and the remaining `X - K` columns.
+    pub fn split_columns<const K: usize>(&self) -> (Matrix<K, Y, T>, Matrix<{ X - K }, Y, T>)
+    where [T; K * Y]: Sized, [T; (X - K) * Y]: Sized, Assert<{ K <= X }>: IsTrue {
+        let mut lhs = Box::new([T::default(); K * Y]);
+        let mut rhs = Box::new([T::default(); (X - K) * Y]);
+
+        for y in 0..Y {
+            let row = &self.data[y * X..(y + 1) * X];
+            lhs[y * K..(y + 1) * K].copy_from_slice(&row[..K]);
+            rhs[y * (X - K)..(y + 1) * (X - K)].copy_from_slice(&row[K..]);
+        }
+
+        (Matrix { data: lhs }, Matrix { data: rhs })
+    }
+
+    /// Splits into the first `K` rows and the remaining `Y - K` rows.
+    pub fn split_rows<const K: usize>(&self) -> (Matrix<X, K, T>, Matrix<X, { Y - K }, T>)
+    where [T; X * K]: Sized, [T; X * (Y - K)]: Sized, Assert<{ K <= Y }>: IsTrue {
+        let mut lhs = Box::new([T::default(); X * K]);
+        let mut rhs = Box::new([T::default(); X * (Y - K)]);
+
+        lhs.copy_from_slice(&self.data[..X * K]);
+        rhs.copy_from_slice(&self.data[X * K..]);
+
+        (Matrix { data: lhs }, Matrix { data: rhs })
+    }
+
+    /// Removes row `I`.
+    pub fn remove_row<const I: usize>(&self) -> Matrix<X, { Y - 1 }, T>
+    where [T; X * (Y - 1)]: Sized, Assert<{ I < Y }>: IsTrue {
+        let mut data = Box::new([T::default(); X * (Y - 1)]);
+
+        data[..I * X].copy_from_slice(&self.data[..I * X]);
+        data[I * X..].copy_from_slice(&self.data[(I + 1) * X..]);
+
+        Matrix { data }
+    }
+
+    /// Removes column `I`.
+    pub fn remove_column<const I: usize>(&self) -> Matrix<{ X - 1 }, Y, T>
+    where [T; (X - 1) * Y]: Sized, Assert<{ I < X }>: IsTrue {
+        let mut data = Box::new([T::default(); (X - 1) * Y]);
+
+        for y in 0..Y {
+            let src = &self.data[y * X..(y + 1) * X];
+            let dest = &mut data[y * (X - 1)..(y + 1) * (X - 1)];
+            dest[..I].copy_from_slice(&src[..I]);
+            dest[I..].copy_from_slice(&src[I + 1..]);
+        }
+
+        Matrix { data }
+    }
+
+    /// Inserts `row` so that it becomes row `I` of the result.
+    pub fn insert_row<const I: usize>(&self, row: &Vector<X, T>) -> Matrix<X, { Y + 1 }, T>
+    where [T; X * (Y + 1)]: Sized, Assert<{ I <= Y }>: IsTrue {
+        let mut data = Box::new([T::default(); X * (Y + 1)]);
+
+        data[..I * X].copy_from_slice(&self.data[..I * X]);
+        data[I * X..(I + 1) * X].copy_from_slice(row.data());
+        data[(I + 1) * X..].copy_from_slice(&self.data[I * X..]);
+
+        Matrix { data }
+    }
+
+    /// Inserts `column` so that it becomes column `I` of the result.
+    pub fn insert_column<const I: usize>(&self, column: &Vector<Y, T>) -> Matrix<{ X + 1 }, Y, T>
+    where [T; (X + 1) * Y]: Sized, Assert<{ I <= X }>: IsTrue {
+        let mut data = Box::new([T::default(); (X + 1) * Y]);
+
+        for (y, value) in column.iter().enumerate() {
+            let src = &self.data[y * X..(y + 1) * X];
+            let dest = &mut data[y * (X + 1)..(y + 1) * (X + 1)];
+            dest[..I].copy_from_slice(&src[..I]);
+            dest[I] = *value;
+            dest[I + 1..].copy_from_slice(&src[I..]);
+        }
+
+        Matrix { data }
+    }
+
+    /// The submatrix with row `I` and column `J` removed.
+    pub fn minor<const I: usize, const J: usize>(&self) -> Matrix<{ X - 1 }, { Y - 1 }, T>
+    where [T; (X - 1) * (Y - 1)]: Sized, Assert<{ X == Y }>: IsTrue, Assert<{ I < Y }>: IsTrue, Assert<{ J < X }>: IsTrue {
+        let mut data = Box::new([T::default(); (X - 1) * (Y - 1)]);
+
+        for (dest, y) in data.chunks_exact_mut(X - 1).zip((0..Y).filter(|&y| y != I)) {
+            let src = &self.data[y * X..(y + 1) * X];
+            dest[..J].copy_from_slice(&src[..J]);
+            dest[J..].copy_from_slice(&src[J + 1..]);
+        }
+
+        Matrix { data }
+    }
 
     #[inline]
     pub fn into_by_column(self) -> IntoByColumn<X, Y, T> {
@@ -327,6 +569,104 @@ where [T; X * Y]: Sized
     }
 }
 
+impl<const X: usize, const Y: usize, T: Number + Neg<Output = T>> Matrix<X, Y, Complex<T>>
+where [Complex<T>; X * Y]: Sized
+{
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        self.map(Complex::conj)
+    }
+
+    #[inline]
+    pub fn conjugate_assign(&mut self) {
+        self.map_assign(Complex::conj);
+    }
+
+    /// The Hermitian transpose `A*`.
+    #[inline]
+    pub fn conjugate_transpose(&self) -> Matrix<Y, X, Complex<T>>
+    where [Complex<T>; Y * X]: Sized {
+        self.transpose_map(Complex::conj)
+    }
+
+    #[inline]
+    pub fn is_hermitian(&self) -> bool
+    where [Complex<T>; Y * X]: Sized {
+        X == Y && self.data[..] == self.conjugate_transpose().data[..]
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Real> Matrix<X, Y, T>
+where [T; X * Y]: Sized
+{
+    #[inline]
+    pub fn frobenius_norm(&self) -> T {
+        self.data.iter().fold(T::ZERO, |acc, value| acc + *value * *value).sqrt()
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Widen> Matrix<X, Y, T>
+where [T; X * Y]: Sized
+{
+    /// Row sums accumulated in `T::Wide`.
+    #[inline]
+    pub fn widening_sum(&self) -> Vector<Y, T::Wide> {
+        self.fold(T::Wide::ZERO, |acc, value| acc + value.widen())
+    }
+
+    #[inline]
+    pub fn widening_sum_column(&self, x: usize) -> T::Wide {
+        self.fold_column(x, T::Wide::ZERO, |acc, value| acc + value.widen())
+    }
+
+    /// Row products accumulated in `T::Wide`.
+    #[inline]
+    pub fn widening_product(&self) -> Vector<Y, T::Wide> {
+        self.fold(T::Wide::ONE, |acc, value| acc * value.widen())
+    }
+
+    #[inline]
+    pub fn widening_product_column(&self, x: usize) -> T::Wide {
+        self.fold_column(x, T::Wide::ONE, |acc, value| acc * value.widen())
+    }
+
+    /// Row averages accumulated in `T::Wide`. Fails instead of panicking if
+    /// `X` is zero or doesn't fit into `T::Wide`.
+    #[inline]
+    pub fn try_avg(&self) -> Result<Vector<Y, T>, TryAvgError>
+    where T::Wide: TryFromUSize {
+        let count = TryAvgError::count::<T::Wide>(X)?;
+        Ok(self.widening_sum().map(|sum| T::narrow(sum / count)))
+    }
+
+    #[inline]
+    pub fn try_avg_column(&self, x: usize) -> Result<T, TryAvgError>
+    where T::Wide: TryFromUSize {
+        let count = TryAvgError::count::<T::Wide>(Y)?;
+        Ok(T::narrow(self.widening_sum_column(x) / count))
+    }
+
+    /// Like `dot()`, but accumulates and returns `T::Wide`.
+    pub fn widening_dot(&self, rhs: &Matrix<Y, X, T>) -> Matrix<Y, Y, T::Wide>
+    where [T; Y * X]: Sized, [T::Wide; Y * Y]: Sized {
+        let mut data = Box::new([T::Wide::ZERO; Y * Y]);
+
+        for y in 0..Y {
+            let lhs_yoffset = X * y;
+            let res_yoffset = Y * y;
+            for x in 0..Y {
+                let mut value = T::Wide::ZERO;
+                for z in 0..X {
+                    value += self.data[lhs_yoffset + z].widen() * rhs.data[z * Y + x].widen();
+                }
+                data[res_yoffset + x] = value;
+            }
+        }
+
+        Matrix { data }
+    }
+}
+
 impl<const X: usize, const Y: usize, T: Number> Tap for Matrix<X, Y, T>
 where [T; X * Y]: Sized {}
 
@@ -348,6 +688,20 @@ where [T; X * Y]: Sized {
         Vector::from(data)
     }
 
+    #[inline]
+    fn aggregate<F, B>(&self, mut f: F) -> Vector<Y, B>
+    where F: FnMut(&mut [T]) -> B, B: Number {
+        let mut row = Vec::with_capacity(X);
+        let mut yoffset = 0;
+        let data = Box::new([(); Y].map(|_| {
+            row.clear();
+            row.extend_from_slice(&self.data[yoffset..yoffset + X]);
+            yoffset += X;
+            f(&mut row)
+        }));
+
+        Vector::from(data)
+    }
 
     #[inline]
     fn mean(&self) -> Vector<Y, T>
@@ -988,6 +1342,129 @@ where [T; X * Y]: Sized, [T; X2 * Y2]: Sized
     }
 }
 
+// ======== SliceAssign ========================================================
+
+impl<const X: usize, const Y: usize, T: Number, RangeX: RangeIter, RangeY: RangeIter> SliceAssign<(RangeX, RangeY)> for Matrix<X, Y, T>
+where [T; X * Y]: Sized, [T; RangeX::LEN * RangeY::LEN]: Sized
+{
+    type Input = Matrix<{ RangeX::LEN }, { RangeY::LEN }, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, ranges: (RangeX, RangeY), values: &Self::Input) {
+        self.assign_slice(&ranges, values);
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, ranges: (RangeX, RangeY), value: T) {
+        self.fill_slice(&ranges, value);
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Number, RangeX: RangeIter, RangeY: RangeIter> SliceAssign<&(RangeX, RangeY)> for Matrix<X, Y, T>
+where [T; X * Y]: Sized, [T; RangeX::LEN * RangeY::LEN]: Sized
+{
+    type Input = Matrix<{ RangeX::LEN }, { RangeY::LEN }, T>;
+    type Element = T;
+
+    fn assign_slice(&mut self, (x_range, y_range): &(RangeX, RangeY), values: &Self::Input) {
+        let x_iter = x_range.iter();
+        let mut src = values.iter();
+        for y in y_range.iter() {
+            let yoffset = y * X;
+            for x in x_iter.clone() {
+                self.data[yoffset + x] = *src.next().unwrap();
+            }
+        }
+    }
+
+    fn fill_slice(&mut self, (x_range, y_range): &(RangeX, RangeY), value: T) {
+        let x_iter = x_range.iter();
+        for y in y_range.iter() {
+            let yoffset = y * X;
+            for x in x_iter.clone() {
+                self.data[yoffset + x] = value;
+            }
+        }
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Number, const N: usize> SliceAssign<[(usize, usize); N]> for Matrix<X, Y, T>
+where [T; X * Y]: Sized, [T; N]: Sized
+{
+    type Input = Vector<N, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, coords: [(usize, usize); N], values: &Self::Input) {
+        self.assign_slice(&coords, values);
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, coords: [(usize, usize); N], value: T) {
+        self.fill_slice(&coords, value);
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Number, const N: usize> SliceAssign<&[(usize, usize); N]> for Matrix<X, Y, T>
+where [T; X * Y]: Sized, [T; N]: Sized
+{
+    type Input = Vector<N, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, coords: &[(usize, usize); N], values: &Self::Input) {
+        for (&(x, y), value) in coords.iter().zip(values.iter()) {
+            self.data[y * X + x] = *value;
+        }
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, coords: &[(usize, usize); N], value: T) {
+        for &(x, y) in coords {
+            self.data[y * X + x] = value;
+        }
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Number, const X2: usize, const Y2: usize> SliceAssign<[[(usize, usize); X2]; Y2]> for Matrix<X, Y, T>
+where [T; X * Y]: Sized, [T; X2 * Y2]: Sized
+{
+    type Input = Matrix<X2, Y2, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, coords: [[(usize, usize); X2]; Y2], values: &Self::Input) {
+        self.assign_slice(&coords, values);
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, coords: [[(usize, usize); X2]; Y2], value: T) {
+        self.fill_slice(&coords, value);
+    }
+}
+
+impl<const X: usize, const Y: usize, T: Number, const X2: usize, const Y2: usize> SliceAssign<&[[(usize, usize); X2]; Y2]> for Matrix<X, Y, T>
+where [T; X * Y]: Sized, [T; X2 * Y2]: Sized
+{
+    type Input = Matrix<X2, Y2, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, coords: &[[(usize, usize); X2]; Y2], values: &Self::Input) {
+        for (&(x, y), value) in coords.iter().flatten().zip(values.iter()) {
+            self.data[y * X + x] = *value;
+        }
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, coords: &[[(usize, usize); X2]; Y2], value: T) {
+        for &(x, y) in coords.iter().flatten() {
+            self.data[y * X + x] = value;
+        }
+    }
+}
+
 // ======== Arithmetic Operations ==============================================
 
 macro_rules! impl_ops {
@@ -2050,4 +2527,144 @@ where [T; N * N]: Sized {
     }
 }
 
+// ======== Elimination ========================================================
+
+impl<const N: usize, T: Abs> Matrix<N, N, T>
+where [T; N * N]: Sized
+{
+    /// Index of the row at or below `start` with the largest absolute value
+    /// in column `x`, or `None` if all of them are zero. Values that can't be
+    /// compared only get picked if there is no pivot yet.
+    fn find_pivot(&self, x: usize, start: usize) -> Option<usize> {
+        let mut pivot = None;
+        let mut max = T::ZERO;
+
+        for y in start..N {
+            let value = self.data[y * N + x].abs();
+            if value != T::ZERO && (pivot.is_none() || value > max) {
+                max = value;
+                pivot = Some(y);
+            }
+        }
+
+        pivot
+    }
+
+    /// Determinant using fraction-free (Bareiss) elimination, so the result
+    /// is also exact for integer matrices.
+    pub fn determinant(&self) -> T {
+        if N == 0 {
+            return T::ONE;
+        }
+
+        let mut matrix = self.clone();
+        let mut negate = false;
+        let mut prev = T::ONE;
+
+        for k in 0..N - 1 {
+            let Some(pivot) = matrix.find_pivot(k, k) else {
+                return T::ZERO;
+            };
+            if pivot != k {
+                matrix.swap_rows(pivot, k);
+                negate = !negate;
+            }
+
+            let diag = matrix.data[k * N + k];
+            for y in k + 1..N {
+                let factor = matrix.data[y * N + k];
+                for x in k + 1..N {
+                    let value = matrix.data[y * N + x] * diag - factor * matrix.data[k * N + x];
+                    matrix.data[y * N + x] = value / prev;
+                }
+            }
+            prev = diag;
+        }
+
+        let det = matrix.data[N * N - 1];
+        if negate { T::ZERO - det } else { det }
+    }
+
+    /// Gauss-Jordan elimination with partial pivoting. Requires `T` to be a
+    /// field, e.g. floats, `Ratio` or `Complex`. Returns `None` for singular
+    /// matrices.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let mut matrix = self.clone();
+        let mut inverse = Self::unit();
+
+        for k in 0..N {
+            let pivot = matrix.find_pivot(k, k)?;
+            matrix.swap_rows(pivot, k);
+            inverse.swap_rows(pivot, k);
+
+            let diag = matrix.data[k * N + k];
+            for x in 0..N {
+                matrix.data[k * N + x] /= diag;
+                inverse.data[k * N + x] /= diag;
+            }
+
+            for y in 0..N {
+                if y == k {
+                    continue;
+                }
+                let factor = matrix.data[y * N + k];
+                if factor == T::ZERO {
+                    continue;
+                }
+                for x in 0..N {
+                    let value = matrix.data[k * N + x];
+                    matrix.data[y * N + x] -= factor * value;
+                    let value = inverse.data[k * N + x];
+                    inverse.data[y * N + x] -= factor * value;
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+
+    /// Panics if the matrix is singular.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("matrix is singular")
+    }
+
+    /// Solves `self · x = rhs` by Gaussian elimination with partial pivoting.
+    /// Requires `T` to be a field. Returns `None` for singular matrices.
+    pub fn solve(&self, rhs: &Vector<N, T>) -> Option<Vector<N, T>> {
+        let mut matrix = self.clone();
+        let mut result = rhs.clone();
+
+        for k in 0..N {
+            let pivot = matrix.find_pivot(k, k)?;
+            matrix.swap_rows(pivot, k);
+            result.data_mut().swap(pivot, k);
+
+            let diag = matrix.data[k * N + k];
+            for y in k + 1..N {
+                let factor = matrix.data[y * N + k] / diag;
+                if factor == T::ZERO {
+                    continue;
+                }
+                for x in k..N {
+                    let value = matrix.data[k * N + x];
+                    matrix.data[y * N + x] -= factor * value;
+                }
+                let value = result[k];
+                result[y] -= factor * value;
+            }
+        }
+
+        for k in (0..N).rev() {
+            let mut value = result[k];
+            for x in k + 1..N {
+                value -= matrix.data[k * N + x] * result[x];
+            }
+            result[k] = value / matrix.data[k * N + k];
+        }
+
+        Some(result)
+    }
+}
+
 // TODO: impl Cross, CrossAssign, EigenValue, EigenVector etc.
@@ -0,0 +1,238 @@
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::fmt::Display;
+
+use crate::number::{Number, FromUSize, Abs};
+use crate::ops::{Pow, PowAssign};
+use crate::assert::{Assert, IsTrue};
+
+// ======== ModP ===============================================================
+
+/// Integer modulo `P`, always kept in `0..P`.
+///
+/// `P` has to be prime for division to be defined for every non-zero
+/// element. Otherwise dividing by an element without an inverse panics.
+/// The order is the order of the representatives and has no algebraic
+/// meaning. `P` has to be at least 2.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ModP<const P: u64>
+where Assert<{ P > 1 }>: IsTrue {
+    value: u64
+}
+
+impl<const P: u64> ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    pub const P: u64 = P;
+
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Self { value: value % P }
+    }
+
+    /// Maps negative values to their positive representative.
+    #[inline]
+    pub const fn from_i64(value: i64) -> Self {
+        Self { value: (value as i128).rem_euclid(P as i128) as u64 }
+    }
+
+    #[inline]
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Multiplicative inverse using the extended Euclidean algorithm, or
+    /// `None` if `self` and `P` aren't coprime.
+    pub const fn inverse(self) -> Option<Self> {
+        let (mut r0, mut r1) = (P as i128, self.value as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+
+        while r1 != 0 {
+            let quot = r0 / r1;
+            (r0, r1) = (r1, r0 - quot * r1);
+            (t0, t1) = (t1, t0 - quot * t1);
+        }
+
+        if r0 != 1 {
+            return None;
+        }
+
+        Some(Self { value: t0.rem_euclid(P as i128) as u64 })
+    }
+}
+
+/// Residues have no sign, so this is the identity.
+impl<const P: u64> Abs for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn abs(self) -> Self {
+        self
+    }
+}
+
+impl<const P: u64> Number for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    const ZERO: Self = ModP { value: 0 };
+    const ONE: Self = ModP { value: 1 };
+}
+
+impl<const P: u64> FromUSize for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl<const P: u64> From<u64> for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<ModP<P>> for u64
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn from(value: ModP<P>) -> Self {
+        value.value
+    }
+}
+
+impl<const P: u64> Display for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<const P: u64> Add for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { value: ((self.value as u128 + rhs.value as u128) % P as u128) as u64 }
+    }
+}
+
+impl<const P: u64> Sub for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add(-rhs)
+    }
+}
+
+impl<const P: u64> Mul for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { value: ((self.value as u128 * rhs.value as u128) % P as u128) as u64 }
+    }
+}
+
+impl<const P: u64> Div for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    /// Panics if `rhs` has no inverse.
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.mul(rhs.inverse().expect("element has no inverse"))
+    }
+}
+
+impl<const P: u64> Neg for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { value: if self.value == 0 { 0 } else { P - self.value } }
+    }
+}
+
+impl<const P: u64> AddAssign for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> DivAssign for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> Pow<u32> for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    /// Exponentiation by squaring.
+    fn pow(self, mut rhs: u32) -> Self::Output {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while rhs > 0 {
+            if rhs & 1 != 0 {
+                result *= base;
+            }
+            base *= base;
+            rhs >>= 1;
+        }
+        result
+    }
+}
+
+impl<const P: u64> Pow<i32> for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    type Output = Self;
+
+    /// Negative exponents use the inverse and panic if there is none.
+    #[inline]
+    fn pow(self, rhs: i32) -> Self::Output {
+        let result = self.pow(rhs.unsigned_abs());
+        if rhs < 0 { Self::ONE / result } else { result }
+    }
+}
+
+impl<const P: u64> PowAssign<u32> for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn pow_assign(&mut self, rhs: u32) {
+        *self = self.pow(rhs)
+    }
+}
+
+impl<const P: u64> PowAssign<i32> for ModP<P>
+where Assert<{ P > 1 }>: IsTrue {
+    #[inline]
+    fn pow_assign(&mut self, rhs: i32) {
+        *self = self.pow(rhs)
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, MulAssign, SubAssign, DivAssign};
 use std::fmt::{Display, Debug};
 
 use crate::ops::{Pow, PowAssign};
@@ -22,6 +22,71 @@ pub trait FromUSize {
     fn from_usize(value: usize) -> Self;
 }
 
+/// Fallible version of `FromUSize` for types that can't represent every
+/// `usize`.
+pub trait TryFromUSize: Sized {
+    fn try_from_usize(value: usize) -> Result<Self, TryFromUSizeError>;
+}
+
+/// The value passed to `TryFromUSize::try_from_usize()` was out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryFromUSizeError {
+    pub value: usize
+}
+
+impl Display for TryFromUSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is out of range for the target type", self.value)
+    }
+}
+
+impl std::error::Error for TryFromUSizeError {}
+
+/// Why `try_avg()` couldn't compute an average.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TryAvgError {
+    /// There are no values to average.
+    Empty,
+    /// The number of values doesn't fit into the accumulator type.
+    OutOfRange(TryFromUSizeError),
+}
+
+impl TryAvgError {
+    /// Number of values as `T`, or why it can't be divided by.
+    #[inline]
+    pub(crate) fn count<T: TryFromUSize>(count: usize) -> Result<T, Self> {
+        if count == 0 {
+            return Err(TryAvgError::Empty);
+        }
+        T::try_from_usize(count).map_err(TryAvgError::OutOfRange)
+    }
+}
+
+impl From<TryFromUSizeError> for TryAvgError {
+    #[inline]
+    fn from(value: TryFromUSizeError) -> Self {
+        TryAvgError::OutOfRange(value)
+    }
+}
+
+impl Display for TryAvgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryAvgError::Empty => f.write_str("average of no values"),
+            TryAvgError::OutOfRange(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for TryAvgError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TryAvgError::Empty => None,
+            TryAvgError::OutOfRange(error) => Some(error),
+        }
+    }
+}
+
 impl Number for i8 {
     const ZERO: Self = 0;
     const ONE: Self = 1;
@@ -349,3 +414,520 @@ impl PowAssign<i32> for f64 {
         *self = self.powi(rhs)
     }
 }
+
+// ======== Widen ==============================================================
+
+/// A wider type for accumulating sums, products and dot products without
+/// losing precision or overflowing.
+pub trait Widen: Number {
+    type Wide: Number;
+
+    fn widen(self) -> Self::Wide;
+
+    /// Converts back, rounding like `as` casts do.
+    fn narrow(value: Self::Wide) -> Self;
+}
+
+impl Widen for f32 {
+    type Wide = f64;
+
+    #[inline]
+    fn widen(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn narrow(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl Widen for f64 {
+    type Wide = f64;
+
+    #[inline]
+    fn widen(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn narrow(value: f64) -> Self {
+        value
+    }
+}
+
+macro_rules! impl_widen_int {
+    ($($type:ident $wide:ident),*) => {
+        $(
+            impl Widen for $type {
+                type Wide = $wide;
+
+                #[inline]
+                fn widen(self) -> $wide {
+                    self as $wide
+                }
+
+                #[inline]
+                fn narrow(value: $wide) -> Self {
+                    value as $type
+                }
+            }
+        )*
+    };
+}
+
+impl_widen_int!(
+    i8 i64, i16 i64, i32 i64, i64 i128, i128 i128, isize i128,
+    u8 u64, u16 u64, u32 u64, u64 u128, u128 u128, usize u128
+);
+
+// ======== TryFromUSize =======================================================
+
+macro_rules! impl_try_from_usize {
+    ($($type:ident)*) => {
+        $(
+            impl TryFromUSize for $type {
+                #[inline]
+                fn try_from_usize(value: usize) -> Result<Self, TryFromUSizeError> {
+                    value.try_into().map_err(|_| TryFromUSizeError { value })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_usize!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl TryFromUSize for f32 {
+    #[inline]
+    fn try_from_usize(value: usize) -> Result<Self, TryFromUSizeError> {
+        Ok(value as f32)
+    }
+}
+
+impl TryFromUSize for f64 {
+    #[inline]
+    fn try_from_usize(value: usize) -> Result<Self, TryFromUSizeError> {
+        Ok(value as f64)
+    }
+}
+
+// ======== Abs/Signed =========================================================
+
+pub trait Abs: Number {
+    fn abs(self) -> Self;
+}
+
+/// Signed integers and floating point numbers.
+pub trait Signed: Abs + Neg<Output = Self> {
+    /// `-1`, `0` or `1` for integers. Floats follow `f64::signum()`.
+    fn signum(self) -> Self;
+
+    #[inline]
+    fn is_positive(self) -> bool {
+        self > Self::ZERO
+    }
+
+    #[inline]
+    fn is_negative(self) -> bool {
+        self < Self::ZERO
+    }
+}
+
+macro_rules! impl_abs_unsigned {
+    ($($type:ident)*) => {
+        $(
+            impl Abs for $type {
+                #[inline]
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_unsigned!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_signed {
+    ($($type:ident)*) => {
+        $(
+            impl Abs for $type {
+                #[inline]
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+            }
+
+            impl Signed for $type {
+                #[inline]
+                fn signum(self) -> Self {
+                    self.signum()
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8 i16 i32 i64 i128 isize f32 f64);
+
+// ======== Real ===============================================================
+
+/// Floating point numbers, so that generic code can use `sqrt()`, the
+/// trigonometric functions, `EPSILON` etc.
+pub trait Real: Signed + FromUSize + Pow<i32, Output = Self> + Pow<Self, Output = Self> {
+    const EPSILON: Self;
+    const MIN_POSITIVE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+    const PI: Self;
+    const E: Self;
+    const LN_2: Self;
+    const LN_10: Self;
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    /// Rounds to nearest if `Self` is less precise.
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+
+    fn recip(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log(self, base: Self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($type:ident $(, $fn:ident($($arg:ident: $arg_type:ty),*) -> $ret:ty)*) => {
+        impl Real for $type {
+            const EPSILON: Self = $type::EPSILON;
+            const MIN_POSITIVE: Self = $type::MIN_POSITIVE;
+            const MIN: Self = $type::MIN;
+            const MAX: Self = $type::MAX;
+            const INFINITY: Self = $type::INFINITY;
+            const NEG_INFINITY: Self = $type::NEG_INFINITY;
+            const NAN: Self = $type::NAN;
+            const PI: Self = std::$type::consts::PI;
+            const E: Self = std::$type::consts::E;
+            const LN_2: Self = std::$type::consts::LN_2;
+            const LN_10: Self = std::$type::consts::LN_10;
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            $(
+                #[inline]
+                fn $fn(self $(, $arg: $arg_type)*) -> $ret {
+                    self.$fn($($arg),*)
+                }
+            )*
+        }
+    };
+    ($($type:ident)*) => {
+        $(
+            impl_real!($type,
+                is_nan() -> bool, is_finite() -> bool, is_infinite() -> bool,
+                floor() -> Self, ceil() -> Self, round() -> Self, trunc() -> Self, fract() -> Self,
+                min(other: Self) -> Self, max(other: Self) -> Self, clamp(min: Self, max: Self) -> Self,
+                copysign(sign: Self) -> Self,
+                recip() -> Self, mul_add(a: Self, b: Self) -> Self, sqrt() -> Self, cbrt() -> Self,
+                hypot(other: Self) -> Self, powi(n: i32) -> Self, powf(n: Self) -> Self,
+                exp() -> Self, exp2() -> Self, ln() -> Self, log(base: Self) -> Self, log2() -> Self, log10() -> Self,
+                sin() -> Self, cos() -> Self, tan() -> Self, sin_cos() -> (Self, Self),
+                asin() -> Self, acos() -> Self, atan() -> Self, atan2(other: Self) -> Self,
+                sinh() -> Self, cosh() -> Self, tanh() -> Self
+            );
+        )*
+    };
+}
+
+impl_real!(f32 f64);
+
+// ======== Complex ============================================================
+
+/// Complex number `re + im·i`.
+///
+/// Complex numbers have no natural order, so `partial_cmp()` only compares
+/// numbers with a zero imaginary part and returns `None` otherwise.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct Complex<T: Number=f64> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Number> Complex<T> {
+    pub const I: Self = Complex { re: T::ZERO, im: T::ONE };
+
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    #[inline]
+    pub fn conj(self) -> Self
+    where T: Neg<Output = T> {
+        Self { re: self.re, im: -self.im }
+    }
+
+    /// `|z|²`, which doesn't need a square root.
+    #[inline]
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    #[inline]
+    pub fn is_real(self) -> bool {
+        self.im == T::ZERO
+    }
+}
+
+impl<T: Real> Complex<T> {
+    #[inline]
+    pub fn from_polar(r: T, theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self { re: r * cos, im: r * sin }
+    }
+
+    #[inline]
+    pub fn norm(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    #[inline]
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+}
+
+/// The magnitude as a real-valued complex number, so that it can be compared.
+impl<T: Real> Abs for Complex<T> {
+    #[inline]
+    fn abs(self) -> Self {
+        Complex::new(self.norm(), T::ZERO)
+    }
+}
+
+impl<T: Number> Number for Complex<T> {
+    const ZERO: Self = Complex { re: T::ZERO, im: T::ZERO };
+    const ONE: Self = Complex { re: T::ONE, im: T::ZERO };
+}
+
+impl<T: Number + FromUSize> FromUSize for Complex<T> {
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        Self { re: T::from_usize(value), im: T::ZERO }
+    }
+}
+
+impl<T: Number> From<T> for Complex<T> {
+    #[inline]
+    fn from(re: T) -> Self {
+        Self { re, im: T::ZERO }
+    }
+}
+
+impl<T: Number> From<(T, T)> for Complex<T> {
+    #[inline]
+    fn from((re, im): (T, T)) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Number> PartialOrd for Complex<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.im == T::ZERO && other.im == T::ZERO {
+            self.re.partial_cmp(&other.re)
+        } else if self == other {
+            Some(std::cmp::Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Number> Display for Complex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.re, f)?;
+        if self.im.partial_cmp(&T::ZERO) != Some(std::cmp::Ordering::Less) {
+            f.write_str("+")?;
+        }
+        Display::fmt(&self.im, f)?;
+        f.write_str("i")
+    }
+}
+
+impl<T: Number> Add for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl<T: Number> Sub for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl<T: Number> Mul for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl<T: Number> Div for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        let norm_sqr = rhs.norm_sqr();
+        Self {
+            re: (self.re * rhs.re + self.im * rhs.im) / norm_sqr,
+            im: (self.im * rhs.re - self.re * rhs.im) / norm_sqr,
+        }
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { re: -self.re, im: -self.im }
+    }
+}
+
+impl<T: Number> AddAssign for Complex<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Number> SubAssign for Complex<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Number> MulAssign for Complex<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Number> DivAssign for Complex<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Number> Pow<u32> for Complex<T> {
+    type Output = Self;
+
+    /// Exponentiation by squaring.
+    fn pow(self, mut rhs: u32) -> Self::Output {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while rhs > 0 {
+            if rhs & 1 != 0 {
+                result *= base;
+            }
+            base *= base;
+            rhs >>= 1;
+        }
+        result
+    }
+}
+
+impl<T: Number> Pow<i32> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, rhs: i32) -> Self::Output {
+        let result = self.pow(rhs.unsigned_abs());
+        if rhs < 0 { Self::ONE / result } else { result }
+    }
+}
+
+impl<T: Number> PowAssign<u32> for Complex<T> {
+    #[inline]
+    fn pow_assign(&mut self, rhs: u32) {
+        *self = self.pow(rhs)
+    }
+}
+
+impl<T: Number> PowAssign<i32> for Complex<T> {
+    #[inline]
+    fn pow_assign(&mut self, rhs: i32) {
+        *self = self.pow(rhs)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{Number, Vector, FromUSize};
+use crate::{Number, Vector, FromUSize, Real};
+use crate::stats::{self, TotalOrd, Interpolation};
 
 pub trait Unit {
     fn unit() -> Self;
@@ -70,6 +71,16 @@ pub trait Slice<Idx: ?Sized> {
     fn slice(&self, index: Idx) -> Self::Output;
 }
 
+/// The scatter counterpart of [`Slice`]: writes into the positions that
+/// `slice()` would read from.
+pub trait SliceAssign<Idx: ?Sized> {
+    type Input;
+    type Element;
+
+    fn assign_slice(&mut self, index: Idx, values: &Self::Input);
+    fn fill_slice(&mut self, index: Idx, value: Self::Element);
+}
+
 pub trait Tap {
     #[inline]
     fn tap<F>(&self, f: F)
@@ -124,6 +135,64 @@ pub trait MatrixAggregate<const X: usize, const Y: usize, T: Number> {
         self.sum() / T::from_usize(X)
     }
 
+    /// Calls `f` with a scratch copy of each row, or of each column for
+    /// `by_column()`.
+    ///
+    /// The default implementation collects all values with `fold()` first.
+    fn aggregate<F, B>(&self, mut f: F) -> Vector<Y, B>
+    where F: FnMut(&mut [T]) -> B, B: Number {
+        let mut values = Vec::with_capacity(X * Y);
+        self.fold(0u8, |acc, value| {
+            values.push(value);
+            acc
+        });
+
+        let mut offset = 0;
+        let data = Box::new([(); Y].map(|_| {
+            let value = f(&mut values[offset..offset + X]);
+            offset += X;
+            value
+        }));
+
+        Vector::from(data)
+    }
+
+    #[deprecated(note = "this computes the median, use `median()` instead")]
     fn mean(&self) -> Vector<Y, T>
     where T: Ord, [T; Y * X]: Sized;
+
+    /// `NaN` for rows containing `NaN`. Panics if `X == 0`.
+    #[inline]
+    fn median(&self) -> Vector<Y, T>
+    where T: TotalOrd {
+        self.aggregate(stats::median)
+    }
+
+    /// Divides by `X - ddof`, so `ddof = 0` gives the population variance and
+    /// `ddof = 1` the sample variance. Panics if `ddof >= X`.
+    #[inline]
+    fn variance(&self, ddof: usize) -> Vector<Y, T>
+    where T: Real {
+        self.aggregate(|data| stats::variance(data, ddof))
+    }
+
+    #[inline]
+    fn std_dev(&self, ddof: usize) -> Vector<Y, T>
+    where T: Real {
+        self.variance(ddof).map(Real::sqrt)
+    }
+
+    /// Panics if `q` is not in `0..=1` or `X == 0`.
+    #[inline]
+    fn quantile(&self, q: f64, interpolation: Interpolation) -> Vector<Y, T>
+    where T: Real + TotalOrd {
+        self.aggregate(|data| stats::quantile(data, q, interpolation))
+    }
+
+    /// Most frequent value. Ties go to the smallest one. Panics if `X == 0`.
+    #[inline]
+    fn mode(&self) -> Vector<Y, T>
+    where T: Ord {
+        self.aggregate(stats::mode)
+    }
 }
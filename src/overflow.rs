@@ -0,0 +1,269 @@
+use std::num::{Wrapping, Saturating};
+
+use crate::{Matrix, Vector, MaskOperand};
+use crate::number::{Number, FromUSize, Widen};
+use crate::ops::{Pow, PowAssign};
+
+// ======== OverflowOps ========================================================
+
+/// Checked, wrapping and saturating arithmetic of the primitive integer types.
+pub trait OverflowOps: Number {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_overflow_ops {
+    ($($type:ident)*) => {
+        $(
+            impl OverflowOps for $type {
+                const MIN: Self = $type::MIN;
+                const MAX: Self = $type::MAX;
+
+                impl_overflow_ops!(@forward $type Option<Self>:
+                    checked_add checked_sub checked_mul checked_div);
+                impl_overflow_ops!(@forward $type Self:
+                    wrapping_add wrapping_sub wrapping_mul
+                    saturating_add saturating_sub saturating_mul);
+            }
+
+            impl Number for Wrapping<$type> {
+                const ZERO: Self = Wrapping(0);
+                const ONE: Self = Wrapping(1);
+            }
+
+            impl FromUSize for Wrapping<$type> {
+                #[inline]
+                fn from_usize(value: usize) -> Self {
+                    Wrapping(value as $type)
+                }
+            }
+
+            impl Pow<u32> for Wrapping<$type> {
+                type Output = Self;
+
+                #[inline]
+                fn pow(self, rhs: u32) -> Self::Output {
+                    Wrapping(self.0.wrapping_pow(rhs))
+                }
+            }
+
+            impl PowAssign<u32> for Wrapping<$type> {
+                #[inline]
+                fn pow_assign(&mut self, rhs: u32) {
+                    *self = Pow::pow(*self, rhs)
+                }
+            }
+
+            impl Number for Saturating<$type> {
+                const ZERO: Self = Saturating(0);
+                const ONE: Self = Saturating(1);
+            }
+
+            impl FromUSize for Saturating<$type> {
+                #[inline]
+                fn from_usize(value: usize) -> Self {
+                    Saturating(value.try_into().unwrap_or($type::MAX))
+                }
+            }
+
+            impl Pow<u32> for Saturating<$type> {
+                type Output = Self;
+
+                #[inline]
+                fn pow(self, rhs: u32) -> Self::Output {
+                    Saturating(self.0.saturating_pow(rhs))
+                }
+            }
+
+            impl PowAssign<u32> for Saturating<$type> {
+                #[inline]
+                fn pow_assign(&mut self, rhs: u32) {
+                    *self = Pow::pow(*self, rhs)
+                }
+            }
+        )*
+    };
+    (@forward $type:ident $ret:ty: $($name:ident)*) => {
+        $(
+            #[inline]
+            fn $name(self, rhs: Self) -> $ret {
+                $type::$name(self, rhs)
+            }
+        )*
+    };
+}
+
+impl_overflow_ops!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// ======== Matrix/Vector ======================================================
+
+// The right hand side may be a matrix/vector of the same shape or a scalar
+// that is broadcast, same as for the element-wise comparisons.
+macro_rules! impl_elementwise {
+    (checked $($name:ident)*) => {
+        impl<const X: usize, const Y: usize, T: OverflowOps> Matrix<X, Y, T>
+        where [T; X * Y]: Sized
+        {
+            $(
+                /// Returns `None` on overflow.
+                #[inline]
+                pub fn $name<Rhs: MaskOperand<X, Y, T>>(&self, rhs: Rhs) -> Option<Self> {
+                    let mut matrix = self.clone();
+                    for (index, value) in matrix.iter_mut().enumerate() {
+                        *value = value.$name(rhs.element(index))?;
+                    }
+                    Some(matrix)
+                }
+            )*
+        }
+
+        impl<const N: usize, T: OverflowOps> Vector<N, T> {
+            $(
+                /// Returns `None` on overflow.
+                #[inline]
+                pub fn $name<Rhs: MaskOperand<N, 1, T>>(&self, rhs: Rhs) -> Option<Self> {
+                    let mut vector = self.clone();
+                    for (index, value) in vector.iter_mut().enumerate() {
+                        *value = value.$name(rhs.element(index))?;
+                    }
+                    Some(vector)
+                }
+            )*
+        }
+    };
+    ($($name:ident)*) => {
+        impl<const X: usize, const Y: usize, T: OverflowOps> Matrix<X, Y, T>
+        where [T; X * Y]: Sized
+        {
+            $(
+                #[inline]
+                pub fn $name<Rhs: MaskOperand<X, Y, T>>(&self, rhs: Rhs) -> Self {
+                    let mut matrix = self.clone();
+                    for (index, value) in matrix.iter_mut().enumerate() {
+                        *value = value.$name(rhs.element(index));
+                    }
+                    matrix
+                }
+            )*
+        }
+
+        impl<const N: usize, T: OverflowOps> Vector<N, T> {
+            $(
+                #[inline]
+                pub fn $name<Rhs: MaskOperand<N, 1, T>>(&self, rhs: Rhs) -> Self {
+                    let mut vector = self.clone();
+                    for (index, value) in vector.iter_mut().enumerate() {
+                        *value = value.$name(rhs.element(index));
+                    }
+                    vector
+                }
+            )*
+        }
+    };
+}
+
+impl_elementwise!(checked checked_add checked_sub checked_mul checked_div);
+impl_elementwise!(wrapping_add wrapping_sub wrapping_mul);
+impl_elementwise!(saturating_add saturating_sub saturating_mul);
+
+#[inline]
+fn saturating_mul_add<T: Widen>(acc: T::Wide, lhs: T, rhs: T) -> T::Wide
+where T::Wide: OverflowOps {
+    acc.saturating_add(lhs.widen().saturating_mul(rhs.widen()))
+}
+
+#[inline]
+fn saturate<T: OverflowOps + Widen>(value: T::Wide) -> T
+where T::Wide: OverflowOps {
+    if value < T::MIN.widen() {
+        T::MIN
+    } else if value > T::MAX.widen() {
+        T::MAX
+    } else {
+        T::narrow(value)
+    }
+}
+
+impl<const X: usize, const Y: usize, T: OverflowOps> Matrix<X, Y, T>
+where [T; X * Y]: Sized
+{
+    /// Matrix product where `f(acc, lhs, rhs)` accumulates one term in `B`
+    /// and `finish` converts the sum.
+    fn try_dot_by<B, F, G>(&self, rhs: &Matrix<Y, X, T>, mut f: F, mut finish: G) -> Option<Matrix<Y, Y, T>>
+    where [T; Y * X]: Sized, [T; Y * Y]: Sized, B: Number, F: FnMut(B, T, T) -> Option<B>, G: FnMut(B) -> T {
+        let lhs = self.data();
+        let rhs = rhs.data();
+        let mut data = Box::new([T::ZERO; Y * Y]);
+
+        for y in 0..Y {
+            let lhs_yoffset = X * y;
+            let res_yoffset = Y * y;
+            for x in 0..Y {
+                let mut value = B::ZERO;
+                for z in 0..X {
+                    value = f(value, lhs[lhs_yoffset + z], rhs[z * Y + x])?;
+                }
+                data[res_yoffset + x] = finish(value);
+            }
+        }
+
+        Some(Matrix::from(data))
+    }
+
+    /// Returns `None` if any intermediate result overflows.
+    #[inline]
+    pub fn checked_dot(&self, rhs: &Matrix<Y, X, T>) -> Option<Matrix<Y, Y, T>>
+    where [T; Y * X]: Sized, [T; Y * Y]: Sized {
+        self.try_dot_by(rhs, |acc: T, lhs, rhs| acc.checked_add(lhs.checked_mul(rhs)?), |value| value)
+    }
+
+    #[inline]
+    pub fn wrapping_dot(&self, rhs: &Matrix<Y, X, T>) -> Matrix<Y, Y, T>
+    where [T; Y * X]: Sized, [T; Y * Y]: Sized {
+        self.try_dot_by(rhs, |acc: T, lhs, rhs| Some(acc.wrapping_add(lhs.wrapping_mul(rhs))), |value| value).unwrap()
+    }
+
+    /// Accumulates in `T::Wide` and saturates once at the end, so the result
+    /// doesn't depend on the order of the elements. Only if the wide sum
+    /// itself saturates, which needs 64 or 128 bit elements, it does.
+    #[inline]
+    pub fn saturating_dot(&self, rhs: &Matrix<Y, X, T>) -> Matrix<Y, Y, T>
+    where [T; Y * X]: Sized, [T; Y * Y]: Sized, T: Widen, T::Wide: OverflowOps {
+        self.try_dot_by(rhs, |acc, lhs, rhs| Some(saturating_mul_add(acc, lhs, rhs)), saturate).unwrap()
+    }
+}
+
+impl<const N: usize, T: OverflowOps> Vector<N, T> {
+    /// Returns `None` if any intermediate result overflows.
+    #[inline]
+    pub fn checked_dot(&self, rhs: &Self) -> Option<T> {
+        self.iter().zip(rhs.iter()).try_fold(T::ZERO, |acc, (&lhs, &rhs)| acc.checked_add(lhs.checked_mul(rhs)?))
+    }
+
+    #[inline]
+    pub fn wrapping_dot(&self, rhs: &Self) -> T {
+        self.iter().zip(rhs.iter()).fold(T::ZERO, |acc, (&lhs, &rhs)| acc.wrapping_add(lhs.wrapping_mul(rhs)))
+    }
+
+    /// Accumulates in `T::Wide` and saturates once at the end, so the result
+    /// doesn't depend on the order of the elements. Only if the wide sum
+    /// itself saturates, which needs 64 or 128 bit elements, it does.
+    #[inline]
+    pub fn saturating_dot(&self, rhs: &Self) -> T
+    where T: Widen, T::Wide: OverflowOps {
+        saturate(self.iter().zip(rhs.iter()).fold(T::Wide::ZERO, |acc, (&lhs, &rhs)| saturating_mul_add(acc, lhs, rhs)))
+    }
+}
@@ -0,0 +1,606 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Matrix, Vector};
+use crate::number::{Number, Real};
+use crate::ops::Dot;
+
+#[inline]
+const fn lower_index(x: usize, y: usize) -> usize {
+    y * (y + 1) / 2 + x
+}
+
+#[inline]
+const fn upper_index<const N: usize>(x: usize, y: usize) -> usize {
+    y * (2 * N - y + 1) / 2 + x - y
+}
+
+// ======== Symmetric ==========================================================
+
+/// Symmetric `N x N` matrix that only stores its lower triangle
+/// (`N * (N + 1) / 2` elements, row by row).
+///
+/// Indexing with `(x, y)` and `(y, x)` yields the same element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symmetric<const N: usize, T: Number=f64>
+where [T; N * (N + 1) / 2]: Sized
+{
+    data: Box<[T; N * (N + 1) / 2]>
+}
+
+impl<const N: usize, T: Number> Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    pub const N: usize = N;
+    pub const LEN: usize = N * (N + 1) / 2;
+    pub const SHAPE: [usize; 2] = [N, N];
+
+    #[inline]
+    pub fn data(&self) -> &[T; N * (N + 1) / 2] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T; N * (N + 1) / 2] {
+        &mut self.data
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= N || y >= N {
+            return None;
+        }
+        Some(&self.data[if x <= y { lower_index(x, y) } else { lower_index(y, x) }])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= N || y >= N {
+            return None;
+        }
+        Some(&mut self.data[if x <= y { lower_index(x, y) } else { lower_index(y, x) }])
+    }
+
+    pub fn to_matrix(&self) -> Matrix<N, N, T>
+    where [T; N * N]: Sized {
+        let mut matrix = Matrix::default();
+        let data = matrix.data_mut();
+
+        for y in 0..N {
+            for x in 0..=y {
+                let value = self.data[lower_index(x, y)];
+                data[y * N + x] = value;
+                data[x * N + y] = value;
+            }
+        }
+
+        matrix
+    }
+
+    /// Symmetric rank-k update: `self += alpha · A · Aᵀ`, where `A` has `N`
+    /// rows and `K` columns.
+    pub fn rank_k_update<const K: usize>(&mut self, alpha: T, a: &Matrix<K, N, T>)
+    where [T; K * N]: Sized {
+        let a = a.data();
+
+        for y in 0..N {
+            let row_y = &a[y * K..(y + 1) * K];
+            for x in 0..=y {
+                let row_x = &a[x * K..(x + 1) * K];
+                let value = row_y.iter().zip(row_x).fold(T::ZERO, |acc, (&lhs, &rhs)| acc + lhs * rhs);
+                self.data[lower_index(x, y)] += alpha * value;
+            }
+        }
+    }
+
+    /// Symmetric rank-1 update: `self += alpha · v · vᵀ`.
+    pub fn rank_1_update(&mut self, alpha: T, v: &Vector<N, T>) {
+        let v = v.data();
+
+        for y in 0..N {
+            let value = alpha * v[y];
+            let offset = lower_index(0, y);
+            for (dest, &item) in self.data[offset..=offset + y].iter_mut().zip(&v[..=y]) {
+                *dest += value * item;
+            }
+        }
+    }
+}
+
+impl<const N: usize, T: Real> Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    /// Cholesky decomposition `self = L · Lᵀ`.
+    ///
+    /// Returns `None` if the matrix is not positive definite.
+    pub fn cholesky(&self) -> Option<LowerTriangular<N, T>> {
+        let mut res = LowerTriangular::<N, T>::default();
+        let data = res.data_mut();
+
+        for y in 0..N {
+            let row_y = lower_index(0, y);
+            for x in 0..=y {
+                let row_x = lower_index(0, x);
+                let value = self.data[lower_index(x, y)] - data[row_y..row_y + x].iter()
+                    .zip(&data[row_x..row_x + x])
+                    .fold(T::ZERO, |acc, (a, b)| acc + *a * *b);
+
+                if x == y {
+                    if value <= T::ZERO || value.is_nan() {
+                        return None;
+                    }
+                    data[row_y + x] = value.sqrt();
+                } else {
+                    data[row_y + x] = value / data[row_x + x];
+                }
+            }
+        }
+
+        Some(res)
+    }
+}
+
+impl<const N: usize, T: Number> Default for Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self { data: Box::new([T::default(); N * (N + 1) / 2]) }
+    }
+}
+
+impl<const N: usize, T: Number> From<&Matrix<N, N, T>> for Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized, [T; N * N]: Sized
+{
+    /// Copies the lower triangle, the upper triangle is ignored.
+    fn from(value: &Matrix<N, N, T>) -> Self {
+        let mut res = Self::default();
+        let mtx = value.data();
+
+        for y in 0..N {
+            let offset = lower_index(0, y);
+            res.data[offset..=offset + y].copy_from_slice(&mtx[y * N..=y * N + y]);
+        }
+
+        res
+    }
+}
+
+impl<const N: usize, T: Number> From<&Symmetric<N, T>> for Matrix<N, N, T>
+where [T; N * (N + 1) / 2]: Sized, [T; N * N]: Sized
+{
+    #[inline]
+    fn from(value: &Symmetric<N, T>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<const N: usize, T: Number> Index<(usize, usize)> for Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("index out of bounds")
+    }
+}
+
+impl<const N: usize, T: Number> IndexMut<(usize, usize)> for Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("index out of bounds")
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for &Symmetric<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    type Output = Vector<N, T>;
+
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        let rhs = rhs.data();
+        let mut data = Box::new([T::ZERO; N]);
+
+        for y in 0..N {
+            let offset = lower_index(0, y);
+            for (x, &value) in self.data[offset..offset + y].iter().enumerate() {
+                data[y] += value * rhs[x];
+                data[x] += value * rhs[y];
+            }
+            data[y] += self.data[offset + y] * rhs[y];
+        }
+
+        Vector::from(data)
+    }
+}
+
+// ======== LowerTriangular ====================================================
+
+/// Lower triangular `N x N` matrix that only stores the elements on and below
+/// the diagonal (`N * (N + 1) / 2` elements, row by row).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<const N: usize, T: Number=f64>
+where [T; N * (N + 1) / 2]: Sized
+{
+    data: Box<[T; N * (N + 1) / 2]>,
+    // referenced by Index for elements above the diagonal
+    zero: T,
+}
+
+impl<const N: usize, T: Number> LowerTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    pub const N: usize = N;
+    pub const LEN: usize = N * (N + 1) / 2;
+    pub const SHAPE: [usize; 2] = [N, N];
+
+    #[inline]
+    pub fn data(&self) -> &[T; N * (N + 1) / 2] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T; N * (N + 1) / 2] {
+        &mut self.data
+    }
+
+    /// Returns `None` for coordinates above the diagonal.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x > y || y >= N {
+            return None;
+        }
+        Some(&self.data[lower_index(x, y)])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x > y || y >= N {
+            return None;
+        }
+        Some(&mut self.data[lower_index(x, y)])
+    }
+
+    pub fn to_matrix(&self) -> Matrix<N, N, T>
+    where [T; N * N]: Sized {
+        let mut matrix = Matrix::default();
+        let data = matrix.data_mut();
+
+        for y in 0..N {
+            let offset = lower_index(0, y);
+            data[y * N..=y * N + y].copy_from_slice(&self.data[offset..=offset + y]);
+        }
+
+        matrix
+    }
+
+    pub fn transpose(&self) -> UpperTriangular<N, T> {
+        let mut res = UpperTriangular::default();
+
+        for y in 0..N {
+            for x in 0..=y {
+                res.data[upper_index::<N>(y, x)] = self.data[lower_index(x, y)];
+            }
+        }
+
+        res
+    }
+
+    /// Solves `self · x = rhs` by forward substitution.
+    ///
+    /// Returns `None` if an element on the diagonal is zero.
+    pub fn solve(&self, rhs: &Vector<N, T>) -> Option<Vector<N, T>> {
+        let mut res = rhs.clone();
+        let data = res.data_mut();
+
+        for y in 0..N {
+            let offset = lower_index(0, y);
+            let value = self.data[offset..offset + y].iter()
+                .zip(&data[..y])
+                .fold(data[y], |acc, (&a, &b)| acc - a * b);
+
+            let diag = self.data[offset + y];
+            if diag == T::ZERO {
+                return None;
+            }
+            data[y] = value / diag;
+        }
+
+        Some(res)
+    }
+}
+
+impl<const N: usize, T: Number> Default for LowerTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self { data: Box::new([T::default(); N * (N + 1) / 2]), zero: T::ZERO }
+    }
+}
+
+impl<const N: usize, T: Number> From<&Matrix<N, N, T>> for LowerTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized, [T; N * N]: Sized
+{
+    /// Copies the lower triangle, the elements above the diagonal are dropped.
+    fn from(value: &Matrix<N, N, T>) -> Self {
+        let mut res = Self::default();
+        let mtx = value.data();
+
+        for y in 0..N {
+            let offset = lower_index(0, y);
+            res.data[offset..=offset + y].copy_from_slice(&mtx[y * N..=y * N + y]);
+        }
+
+        res
+    }
+}
+
+impl<const N: usize, T: Number> From<&LowerTriangular<N, T>> for Matrix<N, N, T>
+where [T; N * (N + 1) / 2]: Sized, [T; N * N]: Sized
+{
+    #[inline]
+    fn from(value: &LowerTriangular<N, T>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<const N: usize, T: Number> Index<(usize, usize)> for LowerTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        if x > y && x < N {
+            return &self.zero;
+        }
+        self.get(x, y).expect("index out of bounds")
+    }
+}
+
+impl<const N: usize, T: Number> IndexMut<(usize, usize)> for LowerTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    /// Panics for coordinates above the diagonal.
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("index out of bounds or above the diagonal")
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for &LowerTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    type Output = Vector<N, T>;
+
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        let rhs = rhs.data();
+        let mut data = Box::new([T::ZERO; N]);
+
+        for (y, value) in data.iter_mut().enumerate() {
+            let offset = lower_index(0, y);
+            *value = self.data[offset..=offset + y].iter().zip(rhs).fold(T::ZERO, |acc, (&a, &b)| acc + a * b);
+        }
+
+        Vector::from(data)
+    }
+}
+
+// ======== UpperTriangular ====================================================
+
+/// Upper triangular `N x N` matrix that only stores the elements on and above
+/// the diagonal (`N * (N + 1) / 2` elements, row by row).
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<const N: usize, T: Number=f64>
+where [T; N * (N + 1) / 2]: Sized
+{
+    data: Box<[T; N * (N + 1) / 2]>,
+    // referenced by Index for elements below the diagonal
+    zero: T,
+}
+
+impl<const N: usize, T: Number> UpperTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    pub const N: usize = N;
+    pub const LEN: usize = N * (N + 1) / 2;
+    pub const SHAPE: [usize; 2] = [N, N];
+
+    #[inline]
+    pub fn data(&self) -> &[T; N * (N + 1) / 2] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T; N * (N + 1) / 2] {
+        &mut self.data
+    }
+
+    /// Returns `None` for coordinates below the diagonal.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < y || x >= N {
+            return None;
+        }
+        Some(&self.data[upper_index::<N>(x, y)])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < y || x >= N {
+            return None;
+        }
+        Some(&mut self.data[upper_index::<N>(x, y)])
+    }
+
+    pub fn to_matrix(&self) -> Matrix<N, N, T>
+    where [T; N * N]: Sized {
+        let mut matrix = Matrix::default();
+        let data = matrix.data_mut();
+
+        for y in 0..N {
+            let offset = upper_index::<N>(y, y);
+            data[y * N + y..(y + 1) * N].copy_from_slice(&self.data[offset..offset + N - y]);
+        }
+
+        matrix
+    }
+
+    pub fn transpose(&self) -> LowerTriangular<N, T> {
+        let mut res = LowerTriangular::default();
+
+        for y in 0..N {
+            for x in y..N {
+                res.data[lower_index(y, x)] = self.data[upper_index::<N>(x, y)];
+            }
+        }
+
+        res
+    }
+
+    /// Solves `self · x = rhs` by back substitution.
+    ///
+    /// Returns `None` if an element on the diagonal is zero.
+    pub fn solve(&self, rhs: &Vector<N, T>) -> Option<Vector<N, T>> {
+        let mut res = rhs.clone();
+        let data = res.data_mut();
+
+        for y in (0..N).rev() {
+            let offset = upper_index::<N>(y, y);
+            let value = self.data[offset + 1..offset + N - y].iter()
+                .zip(&data[y + 1..])
+                .fold(data[y], |acc, (&a, &b)| acc - a * b);
+
+            let diag = self.data[offset];
+            if diag == T::ZERO {
+                return None;
+            }
+            data[y] = value / diag;
+        }
+
+        Some(res)
+    }
+}
+
+impl<const N: usize, T: Number> Default for UpperTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self { data: Box::new([T::default(); N * (N + 1) / 2]), zero: T::ZERO }
+    }
+}
+
+impl<const N: usize, T: Number> From<&Matrix<N, N, T>> for UpperTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized, [T; N * N]: Sized
+{
+    /// Copies the upper triangle, the elements below the diagonal are dropped.
+    fn from(value: &Matrix<N, N, T>) -> Self {
+        let mut res = Self::default();
+        let mtx = value.data();
+
+        for y in 0..N {
+            let offset = upper_index::<N>(y, y);
+            res.data[offset..offset + N - y].copy_from_slice(&mtx[y * N + y..(y + 1) * N]);
+        }
+
+        res
+    }
+}
+
+impl<const N: usize, T: Number> From<&UpperTriangular<N, T>> for Matrix<N, N, T>
+where [T; N * (N + 1) / 2]: Sized, [T; N * N]: Sized
+{
+    #[inline]
+    fn from(value: &UpperTriangular<N, T>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<const N: usize, T: Number> Index<(usize, usize)> for UpperTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        if x < y && y < N {
+            return &self.zero;
+        }
+        self.get(x, y).expect("index out of bounds")
+    }
+}
+
+impl<const N: usize, T: Number> IndexMut<(usize, usize)> for UpperTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    /// Panics for coordinates below the diagonal.
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("index out of bounds or below the diagonal")
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for &UpperTriangular<N, T>
+where [T; N * (N + 1) / 2]: Sized
+{
+    type Output = Vector<N, T>;
+
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        let rhs = rhs.data();
+        let mut data = Box::new([T::ZERO; N]);
+
+        for (y, value) in data.iter_mut().enumerate() {
+            let offset = upper_index::<N>(y, y);
+            *value = self.data[offset..offset + N - y].iter().zip(&rhs[y..]).fold(T::ZERO, |acc, (&a, &b)| acc + a * b);
+        }
+
+        Vector::from(data)
+    }
+}
+
+// ======== Dot ================================================================
+
+macro_rules! impl_dot {
+    ($type:ident) => {
+        impl<const N: usize, T: Number> Dot<Vector<N, T>> for &$type<N, T>
+        where [T; N * (N + 1) / 2]: Sized
+        {
+            type Output = Vector<N, T>;
+
+            #[inline]
+            fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+                self.dot(&rhs)
+            }
+        }
+
+        impl<const N: usize, T: Number> Dot<&Vector<N, T>> for $type<N, T>
+        where [T; N * (N + 1) / 2]: Sized
+        {
+            type Output = Vector<N, T>;
+
+            #[inline]
+            fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+                (&self).dot(rhs)
+            }
+        }
+
+        impl<const N: usize, T: Number> Dot<Vector<N, T>> for $type<N, T>
+        where [T; N * (N + 1) / 2]: Sized
+        {
+            type Output = Vector<N, T>;
+
+            #[inline]
+            fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+                (&self).dot(&rhs)
+            }
+        }
+    };
+}
+
+impl_dot!(Symmetric);
+impl_dot!(LowerTriangular);
+impl_dot!(UpperTriangular);
@@ -0,0 +1,238 @@
+use std::ops::Index;
+
+use crate::{Matrix, Vector};
+use crate::number::Number;
+use crate::ops::Dot;
+
+// ======== Permutation ========================================================
+
+/// Permutation of `0..N`, stored as an index array.
+///
+/// Element `i` of a permuted vector is element `indices[i]` of the input,
+/// which is the same as multiplying with the matrix that has a one at
+/// `(indices[i], i)` in every row `i`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Permutation<const N: usize> {
+    indices: Box<[usize; N]>
+}
+
+impl<const N: usize> Permutation<N> {
+    pub const N: usize = N;
+
+    #[inline]
+    pub fn identity() -> Self {
+        let mut index = 0;
+        let indices = Box::new([(); N].map(|_| {
+            let value = index;
+            index += 1;
+            value
+        }));
+        Self { indices }
+    }
+
+    /// Returns `None` if `indices` is not a permutation of `0..N`.
+    pub fn try_new(indices: [usize; N]) -> Option<Self> {
+        let mut seen = [false; N];
+        for &index in &indices {
+            if index >= N || seen[index] {
+                return None;
+            }
+            seen[index] = true;
+        }
+        Some(Self { indices: Box::new(indices) })
+    }
+
+    #[inline]
+    pub fn new(indices: [usize; N]) -> Self {
+        Self::try_new(indices).expect("not a permutation")
+    }
+
+    #[inline]
+    pub fn indices(&self) -> &[usize; N] {
+        &self.indices
+    }
+
+    #[inline]
+    pub fn into_indices(self) -> [usize; N] {
+        *self.indices
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<usize> {
+        self.indices.get(index).copied()
+    }
+
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.indices.iter().enumerate().all(|(index, &value)| index == value)
+    }
+
+    /// Swaps the entries `a` and `b`, e.g. to record a pivot step.
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.indices.swap(a, b);
+    }
+
+    /// Applying the result is the same as applying `other` first and then
+    /// `self`, i.e. the matrix product `self · other`.
+    pub fn compose(&self, other: &Permutation<N>) -> Self {
+        Self { indices: Box::new(self.indices.map(|index| other.indices[index])) }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut indices = Box::new([0; N]);
+        for (index, &value) in self.indices.iter().enumerate() {
+            indices[value] = index;
+        }
+        Self { indices }
+    }
+
+    /// Number of cycles, including fixed points.
+    pub fn cycles(&self) -> usize {
+        let mut visited = [false; N];
+        let mut cycles = 0;
+
+        for start in 0..N {
+            if visited[start] {
+                continue;
+            }
+            cycles += 1;
+
+            let mut index = start;
+            while !visited[index] {
+                visited[index] = true;
+                index = self.indices[index];
+            }
+        }
+
+        cycles
+    }
+
+    #[inline]
+    pub fn is_even(&self) -> bool {
+        (N - self.cycles()).is_multiple_of(2)
+    }
+
+    #[inline]
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// `1` for even and `-1` for odd permutations.
+    #[inline]
+    pub fn sign(&self) -> i8 {
+        if self.is_even() { 1 } else { -1 }
+    }
+
+    #[inline]
+    pub fn apply<T: Number>(&self, vector: &Vector<N, T>) -> Vector<N, T> {
+        Vector::from(Box::new(self.indices.map(|index| vector[index])))
+    }
+
+    /// Row `y` of the result is row `indices[y]` of `matrix`.
+    pub fn apply_rows<const X: usize, T: Number>(&self, matrix: &Matrix<X, N, T>) -> Matrix<X, N, T>
+    where [T; X * N]: Sized {
+        let mut data = Box::new([T::default(); X * N]);
+        let src = matrix.data();
+
+        for (row, &index) in data.chunks_exact_mut(X).zip(self.indices.iter()) {
+            row.copy_from_slice(&src[index * X..(index + 1) * X]);
+        }
+
+        Matrix::from(data)
+    }
+
+    /// Column `x` of the result is column `indices[x]` of `matrix`.
+    pub fn apply_columns<const Y: usize, T: Number>(&self, matrix: &Matrix<N, Y, T>) -> Matrix<N, Y, T>
+    where [T; N * Y]: Sized {
+        let mut data = Box::new([T::default(); N * Y]);
+        let src = matrix.data();
+
+        for (dest, src) in data.chunks_exact_mut(N).zip(src.chunks_exact(N)) {
+            for (value, &index) in dest.iter_mut().zip(self.indices.iter()) {
+                *value = src[index];
+            }
+        }
+
+        Matrix::from(data)
+    }
+
+    pub fn to_matrix<T: Number>(&self) -> Matrix<N, N, T>
+    where [T; N * N]: Sized {
+        let mut data = Box::new([T::ZERO; N * N]);
+        for (y, &x) in self.indices.iter().enumerate() {
+            data[y * N + x] = T::ONE;
+        }
+        Matrix::from(data)
+    }
+}
+
+impl<const N: usize> Default for Permutation<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<const N: usize> Index<usize> for Permutation<N> {
+    type Output = usize;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.indices[index]
+    }
+}
+
+impl<const N: usize> TryFrom<[usize; N]> for Permutation<N> {
+    type Error = [usize; N];
+
+    #[inline]
+    fn try_from(indices: [usize; N]) -> Result<Self, Self::Error> {
+        Self::try_new(indices).ok_or(indices)
+    }
+}
+
+impl<const N: usize> From<Permutation<N>> for [usize; N] {
+    #[inline]
+    fn from(value: Permutation<N>) -> Self {
+        value.into_indices()
+    }
+}
+
+impl<const N: usize, T: Number> From<&Permutation<N>> for Matrix<N, N, T>
+where [T; N * N]: Sized {
+    #[inline]
+    fn from(value: &Permutation<N>) -> Self {
+        value.to_matrix()
+    }
+}
+
+// ======== Dot ================================================================
+
+impl<const N: usize> Dot<&Permutation<N>> for &Permutation<N> {
+    type Output = Permutation<N>;
+
+    #[inline]
+    fn dot(self, rhs: &Permutation<N>) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for &Permutation<N> {
+    type Output = Vector<N, T>;
+
+    #[inline]
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+impl<const X: usize, const N: usize, T: Number> Dot<&Matrix<X, N, T>> for &Permutation<N>
+where [T; X * N]: Sized {
+    type Output = Matrix<X, N, T>;
+
+    #[inline]
+    fn dot(self, rhs: &Matrix<X, N, T>) -> Self::Output {
+        self.apply_rows(rhs)
+    }
+}
@@ -0,0 +1,282 @@
+use std::ops::{Add, Mul, Sub, Div, Neg, Rem, AddAssign, MulAssign, SubAssign, DivAssign};
+use std::fmt::{Display, Debug};
+use std::cmp::Ordering;
+
+use crate::number::{Number, FromUSize, Signed, Abs};
+use crate::ops::{Pow, PowAssign};
+
+// ======== Integer ============================================================
+
+/// Signed integer types that can be used as numerator and denominator of a
+/// [`Ratio`].
+pub trait Integer: Signed + Ord + Rem<Output = Self> {
+    #[inline]
+    fn gcd(self, other: Self) -> Self {
+        let mut a = self;
+        let mut b = other;
+        while b != Self::ZERO {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        a.abs()
+    }
+}
+
+impl Integer for i8 {}
+impl Integer for i16 {}
+impl Integer for i32 {}
+impl Integer for i64 {}
+impl Integer for i128 {}
+impl Integer for isize {}
+
+// ======== Ratio ==============================================================
+
+/// Exact rational number. Always kept reduced with a positive denominator,
+/// so the derived `PartialEq` and `Hash` are correct.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Ratio<I: Integer=i64> {
+    numer: I,
+    denom: I,
+}
+
+impl<I: Integer> Ratio<I> {
+    /// Panics if `denom` is zero.
+    #[inline]
+    pub fn new(numer: I, denom: I) -> Self {
+        assert!(denom != I::ZERO, "denominator is zero");
+        Self::new_unchecked(numer, denom).reduced()
+    }
+
+    #[inline]
+    const fn new_unchecked(numer: I, denom: I) -> Self {
+        Self { numer, denom }
+    }
+
+    #[inline]
+    pub const fn from_integer(value: I) -> Self {
+        Self { numer: value, denom: I::ONE }
+    }
+
+    #[inline]
+    pub const fn numer(&self) -> I {
+        self.numer
+    }
+
+    #[inline]
+    pub const fn denom(&self) -> I {
+        self.denom
+    }
+
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.denom == I::ONE
+    }
+
+    /// Panics if `self` is zero.
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::new(self.denom, self.numer)
+    }
+
+    /// Rounds towards zero.
+    #[inline]
+    pub fn trunc(self) -> I {
+        self.numer / self.denom
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.numer < I::ZERO { -self } else { self }
+    }
+
+    fn reduced(self) -> Self {
+        let gcd = self.numer.gcd(self.denom);
+        let (numer, denom) = (self.numer / gcd, self.denom / gcd);
+        if denom < I::ZERO {
+            Self::new_unchecked(-numer, -denom)
+        } else {
+            Self::new_unchecked(numer, denom)
+        }
+    }
+}
+
+impl<I: Integer> Abs for Ratio<I> {
+    #[inline]
+    fn abs(self) -> Self {
+        Ratio::abs(self)
+    }
+}
+
+impl<I: Integer> Number for Ratio<I> {
+    const ZERO: Self = Ratio { numer: I::ZERO, denom: I::ONE };
+    const ONE: Self = Ratio { numer: I::ONE, denom: I::ONE };
+}
+
+impl<I: Integer + FromUSize> FromUSize for Ratio<I> {
+    #[inline]
+    fn from_usize(value: usize) -> Self {
+        Self::from_integer(I::from_usize(value))
+    }
+}
+
+impl<I: Integer> Default for Ratio<I> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<I: Integer> From<I> for Ratio<I> {
+    #[inline]
+    fn from(value: I) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl<I: Integer> From<(I, I)> for Ratio<I> {
+    #[inline]
+    fn from((numer, denom): (I, I)) -> Self {
+        Self::new(numer, denom)
+    }
+}
+
+impl<I: Integer> Display for Ratio<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denom == I::ONE {
+            Display::fmt(&self.numer, f)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<I: Integer> Ord for Ratio<I> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.denom == other.denom {
+            return self.numer.cmp(&other.numer);
+        }
+        let gcd = self.denom.gcd(other.denom);
+        (self.numer * (other.denom / gcd)).cmp(&(other.numer * (self.denom / gcd)))
+    }
+}
+
+impl<I: Integer> PartialOrd for Ratio<I> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: Integer> Add for Ratio<I> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let gcd = self.denom.gcd(rhs.denom);
+        let lhs_factor = rhs.denom / gcd;
+        let rhs_factor = self.denom / gcd;
+        Self::new_unchecked(
+            self.numer * lhs_factor + rhs.numer * rhs_factor,
+            self.denom * lhs_factor,
+        ).reduced()
+    }
+}
+
+impl<I: Integer> Sub for Ratio<I> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<I: Integer> Mul for Ratio<I> {
+    type Output = Self;
+
+    /// Cancels crosswise first to keep intermediate values small.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let gcd1 = self.numer.gcd(rhs.denom);
+        let gcd2 = rhs.numer.gcd(self.denom);
+        Self::new_unchecked(
+            (self.numer / gcd1) * (rhs.numer / gcd2),
+            (self.denom / gcd2) * (rhs.denom / gcd1),
+        )
+    }
+}
+
+impl<I: Integer> Div for Ratio<I> {
+    type Output = Self;
+
+    /// Panics on division by zero.
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.mul(rhs.recip())
+    }
+}
+
+impl<I: Integer> Neg for Ratio<I> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new_unchecked(-self.numer, self.denom)
+    }
+}
+
+impl<I: Integer> AddAssign for Ratio<I> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<I: Integer> SubAssign for Ratio<I> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<I: Integer> MulAssign for Ratio<I> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<I: Integer> DivAssign for Ratio<I> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<I: Integer> Pow<i32> for Ratio<I> {
+    type Output = Self;
+
+    /// Exponentiation by squaring. Panics for a negative exponent of zero.
+    fn pow(self, rhs: i32) -> Self::Output {
+        let mut base = if rhs < 0 { self.recip() } else { self };
+        let mut exp = rhs.unsigned_abs();
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 != 0 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl<I: Integer> PowAssign<i32> for Ratio<I> {
+    #[inline]
+    fn pow_assign(&mut self, rhs: i32) {
+        *self = self.pow(rhs)
+    }
+}
@@ -0,0 +1,248 @@
+use crate::Matrix;
+use crate::number::Number;
+
+// ======== Semiring ===========================================================
+
+/// Addition and multiplication used by `Matrix::semiring_dot()`. The
+/// semirings are marker types, so one element type can be used with several
+/// of them.
+pub trait Semiring<T> {
+    /// Identity of `add()` and annihilator of `mul()`.
+    fn zero() -> T;
+
+    /// Identity of `mul()`.
+    fn one() -> T;
+
+    fn add(lhs: T, rhs: T) -> T;
+    fn mul(lhs: T, rhs: T) -> T;
+}
+
+/// Elements of `MinPlus`. Integers use `MAX` for infinity.
+pub trait Tropical: Number {
+    const INFINITY: Self;
+
+    /// Addition that saturates at the infinities instead of overflowing.
+    fn tropical_add(self, rhs: Self) -> Self;
+}
+
+/// Elements of `MaxPlus`. Signed integers use `MIN` for negative infinity.
+/// Unsigned integers have no room for it, since `MIN` is the `0` weight.
+pub trait SignedTropical: Tropical {
+    const NEG_INFINITY: Self;
+}
+
+macro_rules! impl_tropical {
+    (int $($type:ident)*) => {
+        $(
+            impl Tropical for $type {
+                const INFINITY: Self = $type::MAX;
+
+                #[inline]
+                fn tropical_add(self, rhs: Self) -> Self {
+                    self.saturating_add(rhs)
+                }
+            }
+        )*
+    };
+    (signed $($type:ident)*) => {
+        $(
+            impl SignedTropical for $type {
+                const NEG_INFINITY: Self = $type::MIN;
+            }
+        )*
+    };
+    (float $($type:ident)*) => {
+        $(
+            impl Tropical for $type {
+                const INFINITY: Self = $type::INFINITY;
+
+                #[inline]
+                fn tropical_add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+            }
+
+            impl SignedTropical for $type {
+                const NEG_INFINITY: Self = $type::NEG_INFINITY;
+            }
+        )*
+    };
+}
+
+impl_tropical!(int i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_tropical!(signed i8 i16 i32 i64 i128 isize);
+impl_tropical!(float f32 f64);
+
+/// The usual `(+, ×)`, so `semiring_dot::<Standard>()` is the same as `dot()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Standard;
+
+impl<T: Number> Semiring<T> for Standard {
+    #[inline]
+    fn zero() -> T {
+        T::ZERO
+    }
+
+    #[inline]
+    fn one() -> T {
+        T::ONE
+    }
+
+    #[inline]
+    fn add(lhs: T, rhs: T) -> T {
+        lhs + rhs
+    }
+
+    #[inline]
+    fn mul(lhs: T, rhs: T) -> T {
+        lhs * rhs
+    }
+}
+
+/// `(min, +)` with `INFINITY` for "no edge". Powers of an adjacency matrix
+/// give shortest path lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MinPlus;
+
+impl<T: Tropical> Semiring<T> for MinPlus {
+    #[inline]
+    fn zero() -> T {
+        T::INFINITY
+    }
+
+    #[inline]
+    fn one() -> T {
+        T::ZERO
+    }
+
+    #[inline]
+    fn add(lhs: T, rhs: T) -> T {
+        if rhs < lhs { rhs } else { lhs }
+    }
+
+    #[inline]
+    fn mul(lhs: T, rhs: T) -> T {
+        if lhs == T::INFINITY || rhs == T::INFINITY {
+            T::INFINITY
+        } else {
+            lhs.tropical_add(rhs)
+        }
+    }
+}
+
+/// `(max, +)` with `NEG_INFINITY` for "no edge". Powers of an adjacency
+/// matrix give longest path lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MaxPlus;
+
+impl<T: SignedTropical> Semiring<T> for MaxPlus {
+    #[inline]
+    fn zero() -> T {
+        T::NEG_INFINITY
+    }
+
+    #[inline]
+    fn one() -> T {
+        T::ZERO
+    }
+
+    #[inline]
+    fn add(lhs: T, rhs: T) -> T {
+        if rhs > lhs { rhs } else { lhs }
+    }
+
+    #[inline]
+    fn mul(lhs: T, rhs: T) -> T {
+        if lhs == T::NEG_INFINITY || rhs == T::NEG_INFINITY {
+            T::NEG_INFINITY
+        } else {
+            lhs.tropical_add(rhs)
+        }
+    }
+}
+
+/// `(∨, ∧)` where any non-zero value is true. Results are `ZERO` or `ONE`.
+/// Powers of an adjacency matrix give reachability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Boolean;
+
+impl<T: Number> Semiring<T> for Boolean {
+    #[inline]
+    fn zero() -> T {
+        T::ZERO
+    }
+
+    #[inline]
+    fn one() -> T {
+        T::ONE
+    }
+
+    #[inline]
+    fn add(lhs: T, rhs: T) -> T {
+        if lhs != T::ZERO || rhs != T::ZERO { T::ONE } else { T::ZERO }
+    }
+
+    #[inline]
+    fn mul(lhs: T, rhs: T) -> T {
+        if lhs != T::ZERO && rhs != T::ZERO { T::ONE } else { T::ZERO }
+    }
+}
+
+// ======== Matrix =============================================================
+
+impl<const X: usize, const Y: usize, T: Number> Matrix<X, Y, T>
+where [T; X * Y]: Sized
+{
+    /// Like `dot()`, but with the addition and multiplication of `S`.
+    pub fn semiring_dot<S: Semiring<T>>(&self, rhs: &Matrix<Y, X, T>) -> Matrix<Y, Y, T>
+    where [T; Y * X]: Sized, [T; Y * Y]: Sized {
+        let lhs = self.data();
+        let rhs = rhs.data();
+        let mut data = Box::new([S::zero(); Y * Y]);
+
+        for y in 0..Y {
+            let lhs_yoffset = X * y;
+            let res_yoffset = Y * y;
+            for x in 0..Y {
+                let mut value = S::zero();
+                for z in 0..X {
+                    value = S::add(value, S::mul(lhs[lhs_yoffset + z], rhs[z * Y + x]));
+                }
+                data[res_yoffset + x] = value;
+            }
+        }
+
+        Matrix::from(data)
+    }
+}
+
+impl<const N: usize, T: Number> Matrix<N, N, T>
+where [T; N * N]: Sized
+{
+    /// `one()` on the diagonal and `zero()` elsewhere.
+    pub fn semiring_identity<S: Semiring<T>>() -> Self {
+        let mut data = Box::new([S::zero(); N * N]);
+        for index in 0..N {
+            data[index * N + index] = S::one();
+        }
+        Matrix::from(data)
+    }
+
+    /// `exp`-th power by repeated squaring.
+    pub fn semiring_pow<S: Semiring<T>>(&self, mut exp: u32) -> Self {
+        let mut result = Self::semiring_identity::<S>();
+        let mut base = self.clone();
+
+        while exp > 0 {
+            if exp & 1 != 0 {
+                result = result.semiring_dot::<S>(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.semiring_dot::<S>(&base);
+            }
+        }
+
+        result
+    }
+}
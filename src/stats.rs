@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+
+use crate::{Vector, Real, Ratio, Integer, f16, bf16};
+use crate::number::Number;
+
+// ======== TotalOrd ===========================================================
+
+/// Total order used for sorting in `median()` and `quantile()`. For floats
+/// this is IEEE 754 `totalOrder`, i.e. `-NaN < -∞ < -0 < 0 < ∞ < NaN`.
+pub trait TotalOrd {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_total_ord {
+    ($($type:ident)*) => {
+        $(
+            impl TotalOrd for $type {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_ord!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_total_ord_float {
+    ($($type:ident)*) => {
+        $(
+            impl TotalOrd for $type {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    $type::total_cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_ord_float!(f32 f64);
+
+macro_rules! impl_total_ord_half {
+    ($($type:ident)*) => {
+        $(
+            impl TotalOrd for $type {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    self.to_f32().total_cmp(&other.to_f32())
+                }
+            }
+        )*
+    };
+}
+
+impl_total_ord_half!(f16 bf16);
+
+impl<I: Integer> TotalOrd for Ratio<I> {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(self, other)
+    }
+}
+
+// ======== Interpolation ======================================================
+
+/// How `quantile()` picks a value if the quantile lies between the two data
+/// points `a <= b`. Same as the `method` parameter of numpy's `quantile()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// `a + (b - a) * fraction`
+    #[default]
+    Linear,
+    /// `a`
+    Lower,
+    /// `b`
+    Higher,
+    /// `a` or `b`, whichever is closer. Ties go to the even index.
+    Nearest,
+    /// `(a + b) / 2`
+    Midpoint,
+}
+
+// ======== Helper Functions ===================================================
+
+// These work on a scratch copy and may reorder it.
+
+/// Midpoint of `a <= b` that doesn't overflow. Integers are rounded down if
+/// both have the same sign and towards zero otherwise.
+#[inline]
+fn midpoint<T: Number>(a: T, b: T) -> T {
+    let two = T::ONE + T::ONE;
+    if a < T::ZERO && b > T::ZERO {
+        (a + b) / two
+    } else {
+        a + (b - a) / two
+    }
+}
+
+/// `NaN` if any value is `NaN`.
+pub(crate) fn median<T: Number + TotalOrd>(data: &mut [T]) -> T {
+    assert!(!data.is_empty(), "median of empty data");
+
+    if let Some(&nan) = data.iter().find(|value| value.partial_cmp(value).is_none()) {
+        return nan;
+    }
+
+    data.sort_unstable_by(T::total_cmp);
+    let len = data.len();
+
+    if len & 1 != 0 {
+        data[len / 2]
+    } else {
+        let index = len / 2;
+        midpoint(data[index - 1], data[index])
+    }
+}
+
+/// Divides by `len - ddof`, so `ddof = 0` gives the population variance and
+/// `ddof = 1` the sample variance.
+pub(crate) fn variance<T: Real>(data: &mut [T], ddof: usize) -> T {
+    let len = data.len();
+    assert!(ddof < len, "ddof {} is not less than the number of values {}", ddof, len);
+
+    let mean = data.iter().fold(T::ZERO, |acc, &value| acc + value) / T::from_usize(len);
+    let sum = data.iter().fold(T::ZERO, |acc, &value| {
+        let diff = value - mean;
+        acc + diff * diff
+    });
+
+    sum / T::from_usize(len - ddof)
+}
+
+pub(crate) fn quantile<T: Real + TotalOrd>(data: &mut [T], q: f64, interpolation: Interpolation) -> T {
+    assert!((0.0..=1.0).contains(&q), "quantile {} is not in 0..=1", q);
+    assert!(!data.is_empty(), "quantile of empty data");
+
+    data.sort_unstable_by(T::total_cmp);
+    let pos = q * (data.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let higher = pos.ceil() as usize;
+    let fraction = pos - lower as f64;
+    let a = data[lower];
+    let b = data[higher];
+
+    match interpolation {
+        Interpolation::Linear => {
+            if lower == higher { a } else { a + (b - a) * T::from_f64(fraction) }
+        }
+        Interpolation::Lower => a,
+        Interpolation::Higher => b,
+        Interpolation::Nearest => {
+            if fraction < 0.5 || (fraction == 0.5 && lower & 1 == 0) { a } else { b }
+        }
+        Interpolation::Midpoint => midpoint(a, b),
+    }
+}
+
+/// Most frequent value. Ties go to the smallest one.
+pub(crate) fn mode<T: Number + Ord>(data: &mut [T]) -> T {
+    assert!(!data.is_empty(), "mode of empty data");
+
+    data.sort_unstable();
+
+    let mut best = data[0];
+    let mut best_count = 0;
+    for run in data.chunk_by(|lhs, rhs| lhs == rhs) {
+        if run.len() > best_count {
+            best = run[0];
+            best_count = run.len();
+        }
+    }
+
+    best
+}
+
+// ======== Vector =============================================================
+
+impl<const N: usize, T: Number> Vector<N, T> {
+    /// `NaN` if any value is `NaN`. Panics if `N == 0`.
+    #[inline]
+    pub fn median(&self) -> T
+    where T: TotalOrd {
+        median(&mut self.data().to_vec())
+    }
+
+    /// Divides by `N - ddof`. Panics if `ddof >= N`.
+    #[inline]
+    pub fn variance(&self, ddof: usize) -> T
+    where T: Real {
+        variance(&mut self.data().to_vec(), ddof)
+    }
+
+    #[inline]
+    pub fn std_dev(&self, ddof: usize) -> T
+    where T: Real {
+        self.variance(ddof).sqrt()
+    }
+
+    /// Panics if `q` is not in `0..=1` or `N == 0`.
+    #[inline]
+    pub fn quantile(&self, q: f64, interpolation: Interpolation) -> T
+    where T: Real + TotalOrd {
+        quantile(&mut self.data().to_vec(), q, interpolation)
+    }
+
+    /// Most frequent value. Ties go to the smallest one. Panics if `N == 0`.
+    #[inline]
+    pub fn mode(&self) -> T
+    where T: Ord {
+        mode(&mut self.data().to_vec())
+    }
+}
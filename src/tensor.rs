@@ -0,0 +1,617 @@
+use std::ops::{Add, Mul, Neg, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Index, IndexMut};
+use std::fmt::{Display, Debug};
+
+use crate::{Matrix, FromUSize};
+use crate::assert::{Assert, IsTrue};
+use crate::number::Number;
+use crate::ops::{Pow, PowAssign, Slice, Tap, Pipe};
+use crate::range::{RangeIter, Range};
+
+/// Rank-3 tensor with `Z` layers of `Y` rows of `X` columns each.
+///
+/// The layout is the same as `[[[T; X]; Y]; Z]` and `[Matrix<X, Y, T>; Z]`.
+#[repr(transparent)]
+#[derive(Clone, PartialOrd, Ord)]
+pub struct Tensor3<const X: usize, const Y: usize, const Z: usize, T: Number=f64>
+where [T; X * Y * Z]: Sized
+{
+    data: Box<[T; X * Y * Z]>
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    pub const X: usize = X;
+    pub const Y: usize = Y;
+    pub const Z: usize = Z;
+    pub const SHAPE: [usize; 3] = [Z, Y, X];
+
+    #[inline]
+    pub fn iter(&self) -> impl std::iter::Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl std::iter::Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&T> {
+        if x >= X || y >= Y {
+            return None;
+        }
+        self.data.get((z * Y + y) * X + x)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize, z: usize) -> Option<&mut T> {
+        if x >= X || y >= Y {
+            return None;
+        }
+        self.data.get_mut((z * Y + y) * X + x)
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[T; X * Y * Z] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T; X * Y * Z] {
+        &mut self.data
+    }
+
+    #[inline]
+    pub fn into_data(self) -> [T; X * Y * Z] {
+        *self.data
+    }
+
+    #[inline]
+    pub fn as_arrays(&self) -> &[[[T; X]; Y]; Z] {
+        unsafe { &*(self.data.as_ptr() as *const [[[T; X]; Y]; Z]) }
+    }
+
+    #[inline]
+    pub fn as_arrays_mut(&mut self) -> &mut [[[T; X]; Y]; Z] {
+        unsafe { &mut *(self.data.as_mut_ptr() as *mut [[[T; X]; Y]; Z]) }
+    }
+
+    #[inline]
+    pub fn reshape<const X2: usize, const Y2: usize, const Z2: usize>(&self) -> Tensor3<X2, Y2, Z2, T>
+    where [T; X2 * Y2 * Z2]: Sized, Assert<{ X * Y * Z == X2 * Y2 * Z2 }>: IsTrue {
+        let data = &*self.data;
+        Tensor3 { data: Box::new(unsafe { std::mem::transmute_copy(data) }) }
+    }
+
+    #[inline]
+    pub fn into_reshape<const X2: usize, const Y2: usize, const Z2: usize>(self) -> Tensor3<X2, Y2, Z2, T>
+    where [T; X2 * Y2 * Z2]: Sized, Assert<{ X * Y * Z == X2 * Y2 * Z2 }>: IsTrue {
+        // XXX: is this correct?
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Returns a copy of layer `z`.
+    #[inline]
+    pub fn try_matrix(&self, z: usize) -> Option<Matrix<X, Y, T>>
+    where [T; X * Y]: Sized {
+        if z >= Z {
+            return None;
+        }
+        let mut data = Box::new([T::default(); X * Y]);
+        let zoffset = z * X * Y;
+        data.copy_from_slice(&self.data[zoffset..zoffset + X * Y]);
+        Some(Matrix::from(data))
+    }
+
+    #[inline]
+    pub fn matrix(&self, z: usize) -> Matrix<X, Y, T>
+    where [T; X * Y]: Sized {
+        self.try_matrix(z).unwrap()
+    }
+
+    #[inline]
+    pub fn to_matrices(&self) -> [Matrix<X, Y, T>; Z]
+    where [T; X * Y]: Sized {
+        let mut z = 0;
+        [(); Z].map(|_| {
+            let matrix = self.matrix(z);
+            z += 1;
+            matrix
+        })
+    }
+
+    #[inline]
+    pub fn map<F, U>(&self, f: F) -> Tensor3<X, Y, Z, U>
+    where F: FnMut(T) -> U, U: Number, [U; X * Y * Z]: Sized {
+        Tensor3 { data: Box::new(self.data.map(f)) }
+    }
+
+    #[inline]
+    pub fn map_assign<F>(&mut self, mut f: F)
+    where F: FnMut(T) -> T {
+        for x in self.data.iter_mut() {
+            *x = f(*x);
+        }
+    }
+
+    #[inline]
+    pub fn into_map<F>(mut self, f: F) -> Self
+    where F: FnMut(T) -> T {
+        self.map_assign(f);
+        self
+    }
+
+    // ======== Aggregate ======================================================
+
+    /// Folds along the `x` axis, i.e. over every row of every layer.
+    pub fn fold_x<F, B>(&self, init: B, mut f: F) -> Matrix<Y, Z, B>
+    where F: FnMut(B, T) -> B, B: Number, [B; Y * Z]: Sized {
+        let mut offset = 0;
+        let data = Box::new([(); Y * Z].map(|_| {
+            let value = self.data[offset..offset + X].iter().cloned().fold(init, &mut f);
+            offset += X;
+            value
+        }));
+
+        Matrix::from(data)
+    }
+
+    /// Folds along the `y` axis, i.e. over every column of every layer.
+    pub fn fold_y<F, B>(&self, init: B, mut f: F) -> Matrix<X, Z, B>
+    where F: FnMut(B, T) -> B, B: Number, [B; X * Z]: Sized {
+        let mut data = Box::new([init; X * Z]);
+
+        for z in 0..Z {
+            let res = &mut data[z * X..(z + 1) * X];
+            for y in 0..Y {
+                let offset = (z * Y + y) * X;
+                for (acc, value) in res.iter_mut().zip(&self.data[offset..offset + X]) {
+                    *acc = f(*acc, *value);
+                }
+            }
+        }
+
+        Matrix::from(data)
+    }
+
+    /// Folds along the `z` axis, i.e. over the layers.
+    pub fn fold_z<F, B>(&self, init: B, mut f: F) -> Matrix<X, Y, B>
+    where F: FnMut(B, T) -> B, B: Number, [B; X * Y]: Sized {
+        let mut data = Box::new([init; X * Y]);
+
+        for z in 0..Z {
+            let offset = z * X * Y;
+            for (acc, value) in data.iter_mut().zip(&self.data[offset..offset + X * Y]) {
+                *acc = f(*acc, *value);
+            }
+        }
+
+        Matrix::from(data)
+    }
+
+    #[inline]
+    pub fn sum_x(&self) -> Matrix<Y, Z, T>
+    where [T; Y * Z]: Sized {
+        self.fold_x(T::default(), |acc, value| acc + value)
+    }
+
+    #[inline]
+    pub fn sum_y(&self) -> Matrix<X, Z, T>
+    where [T; X * Z]: Sized {
+        self.fold_y(T::default(), |acc, value| acc + value)
+    }
+
+    #[inline]
+    pub fn sum_z(&self) -> Matrix<X, Y, T>
+    where [T; X * Y]: Sized {
+        self.fold_z(T::default(), |acc, value| acc + value)
+    }
+
+    #[inline]
+    pub fn product_x(&self) -> Matrix<Y, Z, T>
+    where [T; Y * Z]: Sized {
+        self.fold_x(T::ONE, |acc, value| acc * value)
+    }
+
+    #[inline]
+    pub fn product_y(&self) -> Matrix<X, Z, T>
+    where [T; X * Z]: Sized {
+        self.fold_y(T::ONE, |acc, value| acc * value)
+    }
+
+    #[inline]
+    pub fn product_z(&self) -> Matrix<X, Y, T>
+    where [T; X * Y]: Sized {
+        self.fold_z(T::ONE, |acc, value| acc * value)
+    }
+
+    #[inline]
+    pub fn avg_x(&self) -> Matrix<Y, Z, T>
+    where T: FromUSize, [T; Y * Z]: Sized {
+        self.sum_x() / T::from_usize(X)
+    }
+
+    #[inline]
+    pub fn avg_y(&self) -> Matrix<X, Z, T>
+    where T: FromUSize, [T; X * Z]: Sized {
+        self.sum_y() / T::from_usize(Y)
+    }
+
+    #[inline]
+    pub fn avg_z(&self) -> Matrix<X, Y, T>
+    where T: FromUSize, [T; X * Y]: Sized {
+        self.sum_z() / T::from_usize(Z)
+    }
+
+    #[inline]
+    pub const fn range_x(&self) -> Range::<0, X> {
+        Range::<0, X>()
+    }
+
+    #[inline]
+    pub const fn range_y(&self) -> Range::<0, Y> {
+        Range::<0, Y>()
+    }
+
+    #[inline]
+    pub const fn range_z(&self) -> Range::<0, Z> {
+        Range::<0, Z>()
+    }
+
+    #[inline]
+    pub const fn range_xyz(&self) -> (Range::<0, X>, Range::<0, Y>, Range::<0, Z>) {
+        (Range::<0, X>(), Range::<0, Y>(), Range::<0, Z>())
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Tap for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized {}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Pipe for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized {}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> IntoIterator for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, { X * Y * Z }>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Default for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn default() -> Self {
+        Self { data: Box::new([T::default(); X * Y * Z]) }
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Debug for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt("Tensor3::from(", f)?;
+        self.as_arrays().fmt(f)?;
+        Display::fmt(&')', f)
+    }
+}
+
+// ======== Equality ===========================================================
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> PartialEq for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> PartialEq<[[[T; X]; Y]; Z]> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn eq(&self, other: &[[[T; X]; Y]; Z]) -> bool {
+        self.as_arrays() == other
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Eq for Tensor3<X, Y, Z, T>
+where T: Eq, [T; X * Y * Z]: Sized {}
+
+// ======== From ===============================================================
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<Box<[T; X * Y * Z]>> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn from(value: Box<[T; X * Y * Z]>) -> Self {
+        Self { data: value }
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<[[[T; X]; Y]; Z]> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn from(value: [[[T; X]; Y]; Z]) -> Self {
+        Tensor3::from(&value)
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<&[[[T; X]; Y]; Z]> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn from(value: &[[[T; X]; Y]; Z]) -> Self {
+        let mut res = Self::default();
+        *res.as_arrays_mut() = *value;
+        res
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<&[Matrix<X, Y, T>; Z]> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized, [T; X * Y]: Sized
+{
+    #[inline]
+    fn from(value: &[Matrix<X, Y, T>; Z]) -> Self {
+        let mut data = Box::new([T::default(); X * Y * Z]);
+        for (chunk, matrix) in data.chunks_exact_mut(X * Y).zip(value) {
+            chunk.copy_from_slice(matrix.data());
+        }
+        Self { data }
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<[Matrix<X, Y, T>; Z]> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized, [T; X * Y]: Sized
+{
+    #[inline]
+    fn from(value: [Matrix<X, Y, T>; Z]) -> Self {
+        Tensor3::from(&value)
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<T> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        Self { data: Box::new([value; X * Y * Z]) }
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<&Tensor3<X, Y, Z, T>> for [Matrix<X, Y, T>; Z]
+where [T; X * Y * Z]: Sized, [T; X * Y]: Sized
+{
+    #[inline]
+    fn from(value: &Tensor3<X, Y, Z, T>) -> Self {
+        value.to_matrices()
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<Tensor3<X, Y, Z, T>> for [Matrix<X, Y, T>; Z]
+where [T; X * Y * Z]: Sized, [T; X * Y]: Sized
+{
+    #[inline]
+    fn from(value: Tensor3<X, Y, Z, T>) -> Self {
+        value.to_matrices()
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> From<Tensor3<X, Y, Z, T>> for [[[T; X]; Y]; Z]
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn from(value: Tensor3<X, Y, Z, T>) -> Self {
+        *value.as_arrays()
+    }
+}
+
+// ======== Index ==============================================================
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Index<(usize, usize, usize)> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y, z): (usize, usize, usize)) -> &Self::Output {
+        &self.as_arrays()[z][y][x]
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> IndexMut<(usize, usize, usize)> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized
+{
+    #[inline]
+    fn index_mut(&mut self, (x, y, z): (usize, usize, usize)) -> &mut Self::Output {
+        &mut self.as_arrays_mut()[z][y][x]
+    }
+}
+
+// ======== Slice ==============================================================
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number, RangeX: RangeIter, RangeY: RangeIter, RangeZ: RangeIter>
+Slice<(RangeX, RangeY, RangeZ)> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized, [T; RangeX::LEN * RangeY::LEN * RangeZ::LEN]: Sized
+{
+    type Output = Tensor3<{ RangeX::LEN }, { RangeY::LEN }, { RangeZ::LEN }, T>;
+
+    #[inline]
+    fn slice(&self, ranges: (RangeX, RangeY, RangeZ)) -> Self::Output {
+        self.slice(&ranges)
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number, RangeX: RangeIter, RangeY: RangeIter, RangeZ: RangeIter>
+Slice<&(RangeX, RangeY, RangeZ)> for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized, [T; RangeX::LEN * RangeY::LEN * RangeZ::LEN]: Sized
+{
+    type Output = Tensor3<{ RangeX::LEN }, { RangeY::LEN }, { RangeZ::LEN }, T>;
+
+    #[inline]
+    fn slice(&self, (x_range, y_range, z_range): &(RangeX, RangeY, RangeZ)) -> Self::Output {
+        let mut data = Box::new([T::default(); RangeX::LEN * RangeY::LEN * RangeZ::LEN]);
+
+        let x_iter = x_range.iter();
+        let y_iter = y_range.iter();
+        let mut index = 0;
+        for z in z_range.iter() {
+            for y in y_iter.clone() {
+                let yoffset = (z * Y + y) * X;
+                for x in x_iter.clone() {
+                    data[index] = self.data[yoffset + x];
+                    index += 1;
+                }
+            }
+        }
+
+        Tensor3 { data }
+    }
+}
+
+// ======== Arithmetic Operations ==============================================
+
+macro_rules! impl_ops {
+    ($trait:ident $trait_assign:ident $op:ident $op_assign:ident $(where $($where:tt)*)?) => {
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait for Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: Self) -> Self::Output {
+                self.$op_assign(&rhs);
+
+                self
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait<&Tensor3<X, Y, Z, T>> for Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: &Self) -> Self::Output {
+                self.$op_assign(rhs);
+
+                self
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait<T> for Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: T) -> Self::Output {
+                self.$op_assign(rhs);
+
+                self
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait<&Tensor3<X, Y, Z, T>> for &Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            type Output = Tensor3<X, Y, Z, T>;
+
+            #[inline]
+            fn $op(self, rhs: &Tensor3<X, Y, Z, T>) -> Self::Output {
+                let mut res = self.clone();
+                res.$op_assign(rhs);
+
+                res
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait<T> for &Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            type Output = Tensor3<X, Y, Z, T>;
+
+            #[inline]
+            fn $op(self, rhs: T) -> Self::Output {
+                let mut res = self.clone();
+                res.$op_assign(rhs);
+
+                res
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait_assign for Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                self.$op_assign(&rhs);
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait_assign<&Tensor3<X, Y, Z, T>> for Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: &Self) {
+                for (lhs, rhs) in self.data.iter_mut().zip(rhs.data.iter()) {
+                    lhs.$op_assign(*rhs);
+                }
+            }
+        }
+
+        impl<const X: usize, const Y: usize, const Z: usize, T: Number> $trait_assign<T> for Tensor3<X, Y, Z, T>
+        where [T; X * Y * Z]: Sized $(, $($where)*)?
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: T) {
+                for lhs in self.data.iter_mut() {
+                    lhs.$op_assign(rhs);
+                }
+            }
+        }
+    };
+}
+
+impl_ops!(Add AddAssign add add_assign);
+impl_ops!(Sub SubAssign sub sub_assign);
+impl_ops!(Mul MulAssign mul mul_assign);
+impl_ops!(Div DivAssign div div_assign);
+impl_ops!(Pow PowAssign pow pow_assign where T: PowAssign + Pow<Output = T>);
+
+// ======== Neg ================================================================
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Neg for Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized, T: Neg<Output = T>
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        for x in self.data.iter_mut() {
+            *x = -*x;
+        }
+        self
+    }
+}
+
+impl<const X: usize, const Y: usize, const Z: usize, T: Number> Neg for &Tensor3<X, Y, Z, T>
+where [T; X * Y * Z]: Sized, T: Neg<Output = T>
+{
+    type Output = Tensor3<X, Y, Z, T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Tensor3 {
+            data: Box::new(self.data.map(|value| -value))
+        }
+    }
+}
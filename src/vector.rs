@@ -2,10 +2,10 @@ use std::ops::{Add, Mul, Neg, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssi
 use std::iter::{Sum, Product, IntoIterator};
 use std::fmt::{Display, Debug};
 
-use crate::{Matrix, FromUSize};
+use crate::{Matrix, FromUSize, TryFromUSize, TryAvgError, Complex, Real, Widen};
 use crate::assert::{IsTrue, Assert};
 use crate::number::Number;
-use crate::ops::{Get, GetMut, Pow, PowAssign, Unit, Slice, Tap, Pipe};
+use crate::ops::{Get, GetMut, Pow, PowAssign, Unit, Dot, Slice, SliceAssign, Tap, Pipe};
 use crate::range::{RangeIter, Range};
 
 #[repr(transparent)]
@@ -115,6 +115,7 @@ impl<const N: usize, T: Number> Vector<N, T> {
     }
 
     #[inline]
+    #[deprecated(note = "this computes the median, use `median()` instead")]
     pub fn mean(&self) -> T
     where T: Ord {
         let mut data = self.data.clone();
@@ -141,12 +142,138 @@ impl<const N: usize, T: Number> Vector<N, T> {
         }
     }
 
+    pub fn concat<const M: usize>(&self, rhs: &Vector<M, T>) -> Vector<{ N + M }, T>
+    where [T; N + M]: Sized {
+        let mut data = Box::new([T::default(); N + M]);
+
+        data[..N].copy_from_slice(&self.data[..]);
+        data[N..].copy_from_slice(&rhs.data[..]);
+
+        Vector { data }
+    }
+
+    /// Splits into the first `K` elements and the remaining `N - K` elements.
+    pub fn split_at<const K: usize>(&self) -> (Vector<K, T>, Vector<{ N - K }, T>)
+    where [T; N - K]: Sized, Assert<{ K <= N }>: IsTrue {
+        let mut lhs = Box::new([T::default(); K]);
+        let mut rhs = Box::new([T::default(); N - K]);
+
+        lhs.copy_from_slice(&self.data[..K]);
+        rhs.copy_from_slice(&self.data[K..]);
+
+        (Vector { data: lhs }, Vector { data: rhs })
+    }
+
+    #[inline]
+    pub fn push(&self, value: T) -> Vector<{ N + 1 }, T>
+    where [T; N + 1]: Sized {
+        let mut data = Box::new([value; N + 1]);
+        data[..N].copy_from_slice(&self.data[..]);
+
+        Vector { data }
+    }
+
+    /// Returns the first `K` elements.
+    #[inline]
+    pub fn truncate<const K: usize>(&self) -> Vector<K, T>
+    where Assert<{ K <= N }>: IsTrue {
+        let mut data = Box::new([T::default(); K]);
+        data.copy_from_slice(&self.data[..K]);
+
+        Vector { data }
+    }
+
+    /// Converts a point into homogeneous coordinates by appending `1`.
+    #[inline]
+    pub fn extend_homogeneous(&self) -> Vector<{ N + 1 }, T>
+    where [T; N + 1]: Sized {
+        self.push(T::ONE)
+    }
+
+    /// Converts homogeneous coordinates back into a point by dividing by the
+    /// last element and dropping it.
+    #[inline]
+    pub fn project_homogeneous(&self) -> Vector<{ N - 1 }, T>
+    where [T; N - 1]: Sized, Assert<{ N >= 1 }>: IsTrue {
+        let w = self.data[N - 1];
+        let mut data = Box::new([T::default(); N - 1]);
+
+        for (dest, value) in data.iter_mut().zip(self.data.iter()) {
+            *dest = *value / w;
+        }
+
+        Vector { data }
+    }
+
     #[inline]
     pub const fn range(&self) -> Range::<0, N> {
         Range::<0, N>()
     }
 }
 
+impl<const N: usize, T: Number + Neg<Output = T>> Vector<N, Complex<T>> {
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        self.map(Complex::conj)
+    }
+
+    /// Inner product `Σ conj(self[i]) · rhs[i]`, conjugating the left hand
+    /// side. Unlike `dot()` this gives `v.inner(&v) == |v|²`.
+    #[inline]
+    pub fn inner(&self, rhs: &Self) -> Complex<T> {
+        self.data.iter().zip(rhs.data.iter()).fold(Complex::ZERO, |acc, (lhs, rhs)| acc + lhs.conj() * *rhs)
+    }
+
+    #[inline]
+    pub fn norm_sqr(&self) -> T {
+        self.data.iter().fold(T::ZERO, |acc, value| acc + value.norm_sqr())
+    }
+}
+
+impl<const N: usize, T: Real> Vector<N, T> {
+    /// Euclidean length.
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.data.iter().fold(T::ZERO, |acc, value| acc + *value * *value).sqrt()
+    }
+
+    /// Scaled to a length of one. A zero vector gives `NaN`s.
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+        self.map(|value| value / norm)
+    }
+}
+
+impl<const N: usize, T: Widen> Vector<N, T> {
+    /// Sum accumulated in `T::Wide`.
+    #[inline]
+    pub fn widening_sum(&self) -> T::Wide {
+        self.data.iter().fold(T::Wide::ZERO, |acc, value| acc + value.widen())
+    }
+
+    /// Product accumulated in `T::Wide`.
+    #[inline]
+    pub fn widening_product(&self) -> T::Wide {
+        self.data.iter().fold(T::Wide::ONE, |acc, value| acc * value.widen())
+    }
+
+    /// Average accumulated in `T::Wide`. Fails instead of panicking if `N`
+    /// is zero or doesn't fit into `T::Wide`.
+    #[inline]
+    pub fn try_avg(&self) -> Result<T, TryAvgError>
+    where T::Wide: TryFromUSize {
+        let count = TryAvgError::count::<T::Wide>(N)?;
+        Ok(T::narrow(self.widening_sum() / count))
+    }
+
+    /// Dot product accumulated in `T::Wide`.
+    #[inline]
+    pub fn widening_dot(&self, rhs: &Self) -> T::Wide {
+        self.data.iter().zip(rhs.data.iter()).fold(T::Wide::ZERO, |acc, (lhs, rhs)| acc + lhs.widen() * rhs.widen())
+    }
+}
+
 impl<const N: usize, T: Number> Tap for Vector<N, T>
 where [T; N]: Sized {}
 
@@ -421,6 +548,45 @@ impl<const N: usize, T: Number> GetMut<usize> for Vector<N, T> {
     }
 }
 
+// ======== Dot ================================================================
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for &Vector<N, T> {
+    type Output = T;
+
+    /// Plain bilinear dot product. Use `inner()` for complex vectors.
+    #[inline]
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        self.data.iter().zip(rhs.data.iter()).fold(T::ZERO, |acc, (lhs, rhs)| acc + *lhs * *rhs)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<Vector<N, T>> for &Vector<N, T> {
+    type Output = T;
+
+    #[inline]
+    fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+        self.dot(&rhs)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<&Vector<N, T>> for Vector<N, T> {
+    type Output = T;
+
+    #[inline]
+    fn dot(self, rhs: &Vector<N, T>) -> Self::Output {
+        (&self).dot(rhs)
+    }
+}
+
+impl<const N: usize, T: Number> Dot<Vector<N, T>> for Vector<N, T> {
+    type Output = T;
+
+    #[inline]
+    fn dot(self, rhs: Vector<N, T>) -> Self::Output {
+        (&self).dot(&rhs)
+    }
+}
+
 // ======== Slice ==============================================================
 
 impl<const N: usize, T: Number, Range: RangeIter> Slice<Range> for Vector<N, T>
@@ -463,6 +629,50 @@ where [T; N]: Sized, [T; M]: Sized
     }
 }
 
+// ======== SliceAssign ========================================================
+
+impl<const N: usize, T: Number, Range: RangeIter> SliceAssign<Range> for Vector<N, T>
+where [T; N]: Sized, [T; Range::LEN]: Sized
+{
+    type Input = Vector<{ Range::LEN }, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, range: Range, values: &Self::Input) {
+        for (index, value) in range.iter().zip(values.iter()) {
+            self.data[index] = *value;
+        }
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, range: Range, value: T) {
+        for index in range.iter() {
+            self.data[index] = value;
+        }
+    }
+}
+
+impl<const N: usize, T: Number, const M: usize> SliceAssign<[usize; M]> for Vector<N, T>
+where [T; N]: Sized, [T; M]: Sized
+{
+    type Input = Vector<M, T>;
+    type Element = T;
+
+    #[inline]
+    fn assign_slice(&mut self, range: [usize; M], values: &Self::Input) {
+        for (index, value) in range.into_iter().zip(values.iter()) {
+            self.data[index] = *value;
+        }
+    }
+
+    #[inline]
+    fn fill_slice(&mut self, range: [usize; M], value: T) {
+        for index in range {
+            self.data[index] = value;
+        }
+    }
+}
+
 // ======== Arithmetic Operations ==============================================
 
 macro_rules! impl_ops {
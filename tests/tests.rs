@@ -2,7 +2,7 @@
 #![feature(generic_const_exprs)]
 use std::ops::MulAssign;
 
-use matrix::{*, ops::{Pow, PowAssign, Slice, Unit}, range::{Range, RangeIter, RangeWithStep}};
+use matrix::{*, ops::{Dot, Pow, PowAssign, Slice, SliceAssign, Unit}, range::{Range, RangeIter, RangeWithStep}};
 
 #[test]
 fn unit() {
@@ -67,7 +67,7 @@ fn transpose() {
     ]);
 
     let m = Matrix::from([[0usize; 0]; 0]);
-//    assert_eq!(m.clone().into_transpose(), m.transpose());
+    assert_eq!(m.clone().into_transpose(), m.transpose());
     let mut m2 = m.clone();
     m2.transpose_assign();
     assert_eq!(m2, m.transpose());
@@ -75,7 +75,7 @@ fn transpose() {
     let m = Matrix::from([[0usize]]);
     let mut m2 = m.clone();
     m2.transpose_assign();
-//    assert_eq!(m.clone().into_transpose(), m.transpose());
+    assert_eq!(m.clone().into_transpose(), m.transpose());
     assert_eq!(m2, m.transpose());
 
     macro_rules! test_transpose {
@@ -92,12 +92,11 @@ fn transpose() {
         };
 
         (($x:literal $y:literal $($opt:ident)?) $($tail:tt)*) => {
-//            let v = Range::<0, { $x * $y }>().to_vector();
-//            let m: Matrix<$x, $y, usize> = Matrix::from(v);
+            let v = Range::<0, { $x * $y }>().to_vector();
+            let m: Matrix<$x, $y, usize> = Matrix::from(v);
             test_transpose!(@opt $x $($opt)?);
 
-//            println!("into_transpose {} x {}", $x, $y);
-//            assert_eq!(m.clone().into_transpose(), m.transpose());
+            assert_eq!(m.clone().into_transpose(), m.transpose());
             test_transpose!($($tail)*);
         };
     }
@@ -117,6 +116,9 @@ fn transpose() {
         (4 4 sym)
         (4 5) (5 4)
         (5 5 sym)
+        (7 13) (13 7)
+        (64 3) (3 64)
+        (100 37)
     );
 
     /*
@@ -368,3 +370,1174 @@ Matrix::from([
 }
 
 // TODO: many more
+
+#[test]
+fn tridiagonal() {
+    let m: Tridiagonal<4> = Tridiagonal::new(
+        [0.0, 1.0, 1.0, 1.0],
+        [4.0, 4.0, 4.0, 4.0],
+        [1.0, 1.0, 1.0, 0.0],
+    );
+
+    assert_eq!(m.to_matrix(), [
+        [4.0, 1.0, 0.0, 0.0],
+        [1.0, 4.0, 1.0, 0.0],
+        [0.0, 1.0, 4.0, 1.0],
+        [0.0, 0.0, 1.0, 4.0],
+    ]);
+    assert_eq!(Tridiagonal::from(&m.to_matrix()), m);
+    assert_eq!(m.get(0, 1), Some(&1.0));
+    assert_eq!(m.get(0, 2), None);
+
+    let x = Vector::from([1.0, 2.0, 3.0, 4.0]);
+    let b = (&m).dot(&x);
+    assert_eq!(b, [6.0, 12.0, 18.0, 19.0]);
+    let res = m.solve(&b).unwrap();
+    for (actual, expected) in res.iter().zip(x.iter()) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+
+    assert_eq!(Tridiagonal::new([0.0, 1.0], [0.0, 1.0], [1.0, 0.0]).solve(&Vector::from([1.0, 1.0])), None);
+}
+
+#[test]
+fn banded() {
+    let dense = Matrix::from([
+        [4, 1, 2, 0, 0],
+        [1, 5, 1, 2, 0],
+        [0, 1, 6, 1, 2],
+        [0, 0, 1, 7, 1],
+        [0, 0, 0, 1, 8],
+    ]);
+
+    let m: Banded<5, 1, 2, i32> = Banded::from(&dense);
+
+    assert_eq!(m.to_matrix(), dense);
+    assert_eq!(m.get(2, 0), Some(&2));
+    assert_eq!(m.get(3, 0), None);
+    assert_eq!(m.get(0, 2), None);
+
+    let x = Vector::from([1, 2, 3, 4, 5]);
+    assert_eq!((&m).dot(&x), [12, 22, 34, 36, 44]);
+
+    let m: Banded<5, 1, 2> = Banded::from(&dense.map(|value| value as f64));
+    let b = m.clone().dot(x.map(|value| value as f64));
+    let res = m.solve(&b).unwrap();
+    for (actual, expected) in res.iter().zip([1.0, 2.0, 3.0, 4.0, 5.0]) {
+        assert!((actual - expected).abs() < 1e-12);
+    }
+
+    let t = Tridiagonal::new([0, 1, 1], [2, 2, 2], [1, 1, 0]);
+    assert_eq!(t.to_banded().to_matrix(), t.to_matrix());
+}
+
+#[test]
+fn packed() {
+    let dense = Matrix::from([
+        [4.0, 2.0, 2.0],
+        [2.0, 5.0, 3.0],
+        [2.0, 3.0, 6.0],
+    ]);
+
+    let mut s: Symmetric<3> = Symmetric::from(&dense);
+    assert_eq!(s.data(), &[4.0, 2.0, 5.0, 2.0, 3.0, 6.0]);
+    assert_eq!(s.to_matrix(), dense);
+    assert_eq!(s[(0, 2)], s[(2, 0)]);
+    s[(2, 1)] = 7.0;
+    assert_eq!(s[(1, 2)], 7.0);
+    s[(1, 2)] = 3.0;
+
+    let l = s.cholesky().unwrap();
+    assert_eq!(l.to_matrix(), [
+        [2.0, 0.0, 0.0],
+        [1.0, 2.0, 0.0],
+        [1.0, 1.0, 2.0],
+    ]);
+    assert_eq!(l[(2, 0)], 0.0);
+    assert_eq!(l.get(2, 0), None);
+    assert!(Symmetric::<2>::from(&Matrix::from([[1.0, 2.0], [2.0, 1.0]])).cholesky().is_none());
+
+    let u = l.transpose();
+    assert_eq!(u.to_matrix(), l.to_matrix().transpose());
+    assert_eq!(u.transpose(), l);
+    assert_eq!(UpperTriangular::from(&u.to_matrix()), u);
+
+    let x = Vector::from([1.0, 2.0, 3.0]);
+    let b = (&s).dot(&x);
+    assert_eq!(b, [14.0, 21.0, 26.0]);
+    assert_eq!((&l).dot(&x), [2.0, 5.0, 9.0]);
+    assert_eq!((&u).dot(&x), [7.0, 7.0, 6.0]);
+    assert_eq!(u.solve(&l.solve(&b).unwrap()), Some(x));
+    assert_eq!(LowerTriangular::<2>::default().solve(&Vector::from([1.0, 1.0])), None);
+
+    let mut s = Symmetric::<3, i32>::default();
+    s.rank_k_update(2, &Matrix::from([
+        [1, 2],
+        [3, 4],
+        [5, 6],
+    ]));
+    assert_eq!(s.to_matrix(), [
+        [10, 22, 34],
+        [22, 50, 78],
+        [34, 78, 122],
+    ]);
+    s.rank_1_update(1, &Vector::from([1, 0, -1]));
+    assert_eq!(s.to_matrix(), [
+        [11, 22, 33],
+        [22, 50, 78],
+        [33, 78, 123],
+    ]);
+}
+
+#[test]
+fn tensor3() {
+    let t: Tensor3<3, 2, 2, i32> = Tensor3::from([
+        [[ 1,  2,  3],
+         [ 4,  5,  6]],
+        [[ 7,  8,  9],
+         [10, 11, 12]],
+    ]);
+
+    assert_eq!(Tensor3::<3, 2, 2, i32>::SHAPE, [2, 2, 3]);
+    assert_eq!(t[(2, 1, 0)], 6);
+    assert_eq!(t.get(0, 0, 1), Some(&7));
+    assert_eq!(t.get(3, 0, 0), None);
+    assert_eq!(t.matrix(1), [[7, 8, 9], [10, 11, 12]]);
+
+    let [a, b]: [Matrix<3, 2, i32>; 2] = t.clone().into();
+    assert_eq!(Tensor3::from([a, b]), t);
+
+    assert_eq!(t.reshape::<2, 3, 2>(), [
+        [[1, 2], [3, 4], [5, 6]],
+        [[7, 8], [9, 10], [11, 12]],
+    ]);
+
+    assert_eq!(t.slice((Range::<1, 3>(), 1, &[1, 0])), [
+        [[11, 12]],
+        [[ 5,  6]],
+    ]);
+
+    assert_eq!(t.sum_x(), [[6, 15], [24, 33]]);
+    assert_eq!(t.sum_y(), [[5, 7, 9], [17, 19, 21]]);
+    assert_eq!(t.sum_z(), [[8, 10, 12], [14, 16, 18]]);
+    assert_eq!(t.avg_x(), [[2, 5], [8, 11]]);
+    assert_eq!(t.fold_z(0, |acc, value| acc.max(value)), [[7, 8, 9], [10, 11, 12]]);
+
+    assert_eq!(&t + &t, t.clone() * 2);
+    assert_eq!((t.clone() - 1).data()[0], 0);
+    assert_eq!((-t).data()[11], -12);
+}
+
+#[test]
+fn block() {
+    let a = Matrix::from([
+        [1, 2],
+        [3, 4],
+    ]);
+    let b = Matrix::from([
+        [5],
+        [6],
+    ]);
+    let c = Matrix::from([
+        [7, 8],
+    ]);
+    let d = Matrix::from([
+        [9],
+    ]);
+
+    let ab = Matrix::hstack(&a, &b);
+    assert_eq!(ab, [
+        [1, 2, 5],
+        [3, 4, 6],
+    ]);
+    assert_eq!(a.vstack(&c), [
+        [1, 2],
+        [3, 4],
+        [7, 8],
+    ]);
+
+    let m = Matrix::from_blocks(&a, &b, &c, &d);
+    assert_eq!(m, [
+        [1, 2, 5],
+        [3, 4, 6],
+        [7, 8, 9],
+    ]);
+    assert_eq!(m, ab.vstack(&c.hstack(&d)));
+
+    let (lhs, rhs) = ab.split_columns::<2>();
+    assert_eq!(lhs, a);
+    assert_eq!(rhs, b);
+
+    let (top, bottom) = m.split_rows::<2>();
+    assert_eq!(top, ab);
+    assert_eq!(bottom, [[7, 8, 9]]);
+
+    let (empty, all) = m.split_rows::<0>();
+    assert_eq!(empty, Matrix::<3, 0, i32>::default());
+    assert_eq!(all, m);
+}
+
+#[test]
+fn vector_resize() {
+    let a = Vector::from([1, 2, 3]);
+    let b = Vector::from([4, 5]);
+
+    let ab = a.concat(&b);
+    assert_eq!(ab, [1, 2, 3, 4, 5]);
+
+    let (lhs, rhs) = ab.split_at::<3>();
+    assert_eq!(lhs, a);
+    assert_eq!(rhs, b);
+
+    assert_eq!(a.push(4), [1, 2, 3, 4]);
+    assert_eq!(ab.truncate::<2>(), [1, 2]);
+    assert_eq!(ab.truncate::<0>(), []);
+
+    let point = Vector::from([1.0, 2.0, 3.0]);
+    let homogeneous = point.extend_homogeneous();
+    assert_eq!(homogeneous, [1.0, 2.0, 3.0, 1.0]);
+    assert_eq!((homogeneous * 2.0).project_homogeneous(), point);
+}
+
+#[test]
+fn remove_insert() {
+    let m = Matrix::from([
+        [1, 2, 3],
+        [4, 5, 6],
+        [7, 8, 9],
+    ]);
+
+    assert_eq!(m.remove_row::<0>(), [[4, 5, 6], [7, 8, 9]]);
+    assert_eq!(m.remove_row::<1>(), [[1, 2, 3], [7, 8, 9]]);
+    assert_eq!(m.remove_column::<2>(), [[1, 2], [4, 5], [7, 8]]);
+    assert_eq!(m.remove_column::<1>(), [[1, 3], [4, 6], [7, 9]]);
+
+    assert_eq!(m.remove_row::<1>().insert_row::<1>(&Vector::from([4, 5, 6])), m);
+    assert_eq!(m.remove_row::<2>().insert_row::<2>(&Vector::from([7, 8, 9])), m);
+    assert_eq!(m.remove_column::<0>().insert_column::<0>(&Vector::from([1, 4, 7])), m);
+    assert_eq!(m.remove_column::<2>().insert_column::<2>(&Vector::from([3, 6, 9])), m);
+
+    assert_eq!(m.minor::<0, 0>(), [[5, 6], [8, 9]]);
+    assert_eq!(m.minor::<1, 2>(), [[1, 2], [7, 8]]);
+    assert_eq!(m.minor::<2, 1>(), [[1, 3], [4, 6]]);
+}
+
+#[test]
+fn row_column_mut() {
+    let mut m = Matrix::from([
+        [1, 2, 3],
+        [4, 5, 6],
+        [7, 8, 9],
+        [10, 11, 12],
+    ]);
+
+    m.row_mut(1)[2] = 60;
+    assert_eq!(m.row(1), [4, 5, 60]);
+
+    let mut column = m.column_mut(1);
+    assert_eq!(column.len(), 4);
+    column[0] = 20;
+    column.map_assign(|value| value * 10);
+    assert_eq!(column.to_vector(), [200, 50, 80, 110]);
+    assert_eq!(m.column(1), [200, 50, 80, 110]);
+    assert!(m.try_column_mut(3).is_none());
+
+    m.set_row(0, &Vector::from([1, 2, 3]));
+    m.set_column(1, &Vector::from([2, 5, 8, 11]));
+    m[(2, 1)] = 6;
+    let orig = m.clone();
+    assert_eq!(m, [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+
+    m.swap_rows(3, 0);
+    assert_eq!(m, [[10, 11, 12], [4, 5, 6], [7, 8, 9], [1, 2, 3]]);
+    m.swap_rows(0, 3);
+    assert_eq!(m, orig);
+
+    m.swap_columns(0, 2);
+    assert_eq!(m, [[3, 2, 1], [6, 5, 4], [9, 8, 7], [12, 11, 10]]);
+    m.swap_columns(2, 0);
+
+    m.permute_rows(&[2, 0, 3, 1]);
+    assert_eq!(m, [[7, 8, 9], [1, 2, 3], [10, 11, 12], [4, 5, 6]]);
+    m.permute_rows(&[1, 3, 0, 2]);
+    assert_eq!(m, orig);
+}
+
+#[test]
+#[should_panic]
+fn permute_rows_invalid() {
+    let mut m = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    m.permute_rows(&[0, 0, 2]);
+}
+
+#[test]
+fn slice_assign() {
+    let mut m = Matrix::from([
+        [1, 2, 3],
+        [4, 5, 6],
+        [7, 8, 9],
+    ]);
+
+    let other = Matrix::from([[10, 20], [30, 40]]);
+    m.assign_slice((Range::<1, 3>(), &[0, 2]), &other);
+    assert_eq!(m, [[1, 10, 20], [4, 5, 6], [7, 30, 40]]);
+    assert_eq!(m.slice((Range::<1, 3>(), &[0, 2])), other);
+
+    m.fill_slice([(0, 0), (2, 2)], 0);
+    assert_eq!(m, [[0, 10, 20], [4, 5, 6], [7, 30, 0]]);
+
+    m.assign_slice([[(0, 1), (1, 1)]], &Matrix::from([[-4, -5]]));
+    assert_eq!(m, [[0, 10, 20], [-4, -5, 6], [7, 30, 0]]);
+
+    m.by_column_mut().fill_slice((Range::<0, 1>(), Range::<0, 3>()), 1);
+    assert_eq!(m, [[1, 1, 1], [-4, -5, 6], [7, 30, 0]]);
+
+    m.by_column_mut().assign_slice([(0, 2), (2, 0)], &Vector::from([3, 7]));
+    assert_eq!(m, [[1, 1, 3], [-4, -5, 6], [7, 30, 0]]);
+    assert_eq!(m.by_column().slice([(0, 2), (2, 0)]), [3, 7]);
+
+    let mut by_column = m.clone().into_by_column();
+    by_column.fill_slice([[(1, 1)]], 5);
+    assert_eq!(by_column.matrix, [[1, 1, 3], [-4, 5, 6], [7, 30, 0]]);
+
+    let mut v = Vector::from([1, 2, 3, 4, 5]);
+    v.assign_slice(Range::<1, 5>().step_by::<2>(), &Vector::from([20, 40]));
+    assert_eq!(v, [1, 20, 3, 40, 5]);
+    v.fill_slice([0, 4], 0);
+    assert_eq!(v, [0, 20, 3, 40, 0]);
+}
+
+#[test]
+fn permutation() {
+    assert!(Permutation::try_new([0, 2, 2]).is_none());
+    assert!(Permutation::try_new([0, 3, 1]).is_none());
+
+    let p = Permutation::new([2, 0, 3, 1]);
+    let q = Permutation::new([1, 0, 2, 3]);
+    let v = Vector::from([10, 20, 30, 40]);
+
+    assert_eq!(p.apply(&v), [30, 10, 40, 20]);
+    assert_eq!(p.inverse().apply(&p.apply(&v)), v);
+    assert!(p.compose(&p.inverse()).is_identity());
+    assert_eq!(p.compose(&q).apply(&v), p.apply(&q.apply(&v)));
+
+    let dense: Matrix<4, 4, i32> = p.to_matrix();
+    assert_eq!(dense, [
+        [0, 0, 1, 0],
+        [1, 0, 0, 0],
+        [0, 0, 0, 1],
+        [0, 1, 0, 0],
+    ]);
+
+    let m = Matrix::from([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+        [13, 14, 15, 16],
+    ]);
+    assert_eq!(p.apply_rows(&m), (&dense).dot(&m));
+    assert_eq!(p.apply_columns(&m), (&m).dot(&dense.transpose()));
+    let mut m2 = m.clone();
+    m2.permute_rows(p.indices());
+    assert_eq!(m2, p.apply_rows(&m));
+
+    assert_eq!(q.sign(), -1);
+    assert_eq!(p.sign(), -1);
+    assert_eq!(p.compose(&q).sign(), 1);
+    assert_eq!(Permutation::<5>::identity().sign(), 1);
+    assert_eq!(Permutation::new([1, 2, 0]).cycles(), 1);
+    assert!(Permutation::new([1, 2, 0]).is_even());
+}
+
+#[test]
+fn mask() {
+    let m = Matrix::from([
+        [1, 5, 3],
+        [7, 2, 9],
+    ]);
+    let threshold = Matrix::from([
+        [2, 2, 2],
+        [8, 8, 8],
+    ]);
+
+    let gt = m.gt(4);
+    assert_eq!(gt, [[false, true, false], [true, false, true]]);
+    assert_eq!(m.le(&threshold), [[true, false, false], [true, true, false]]);
+    assert_eq!(m.eq_elem(2), [[false, false, false], [false, true, false]]);
+    assert_eq!(m.ne_elem(&m), Mask::default());
+    assert_eq!(m.mask(|value| value % 3 == 0), [[false, false, true], [false, false, true]]);
+
+    assert_eq!(gt.count(), 3);
+    assert!(gt.any());
+    assert!(!gt.all());
+    assert!((&gt | !&gt).all());
+    assert!(!(&gt & !gt.clone()).any());
+    assert_eq!(m.lt(3) ^ m.lt(6), [[false, true, true], [false, false, false]]);
+
+    assert_eq!(Matrix::select(&gt, &m, 0), [[0, 5, 0], [7, 0, 9]]);
+    assert_eq!(Matrix::select(&gt, 1, &threshold), [[2, 1, 2], [1, 8, 1]]);
+    assert_eq!(m.masked_fill(&gt, -1), [[1, -1, 3], [-1, 2, -1]]);
+    assert_eq!(gt.to_matrix::<i32>(), [[0, 1, 0], [1, 0, 1]]);
+
+    let v = Vector::from([1.0, -2.0, 3.0]);
+    let negative = v.lt(0.0);
+    assert_eq!(negative, Mask::from([false, true, false]));
+    assert_eq!(v.masked_fill(&negative, 0.0), [1.0, 0.0, 3.0]);
+    assert_eq!(Vector::select(&v.ge(&Vector::from([1.0, 1.0, 5.0])), &v, 0.0), [1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn complex() {
+    let a = Complex::new(1.0, 2.0);
+    let b = Complex::new(3.0, -1.0);
+
+    assert_eq!(a + b, Complex::new(4.0, 1.0));
+    assert_eq!(a * b, Complex::new(5.0, 5.0));
+    assert_eq!(a * b / b, a);
+    assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+    assert_eq!(a.norm_sqr(), 5.0);
+    assert_eq!(Complex::<f64>::I.pow(2u32), -Complex::ONE);
+    assert_eq!(a.pow(-1), Complex::ONE / a);
+    assert_eq!(format!("{}", a), "1+2i");
+    assert_eq!(format!("{}", b), "3-1i");
+    assert_eq!(Complex::new(1, 0).partial_cmp(&Complex::new(2, 0)), Some(std::cmp::Ordering::Less));
+    assert_eq!(Complex::new(1, 1).partial_cmp(&Complex::new(2, 0)), None);
+
+    let m = Matrix::from([
+        [Complex::new(1, 1), Complex::new(2, 0)],
+        [Complex::new(0, -3), Complex::new(4, 5)],
+        [Complex::new(6, 0), Complex::new(0, 7)],
+    ]);
+    assert_eq!(m.conjugate(), [
+        [Complex::new(1, -1), Complex::new(2, 0)],
+        [Complex::new(0, 3), Complex::new(4, -5)],
+        [Complex::new(6, 0), Complex::new(0, -7)],
+    ]);
+    assert_eq!(m.conjugate_transpose(), [
+        [Complex::new(1, -1), Complex::new(0, 3), Complex::new(6, 0)],
+        [Complex::new(2, 0), Complex::new(4, -5), Complex::new(0, -7)],
+    ]);
+    assert!(!m.is_hermitian());
+    assert!(Matrix::from([
+        [Complex::new(2, 0), Complex::new(1, -1)],
+        [Complex::new(1, 1), Complex::new(3, 0)],
+    ]).is_hermitian());
+
+    let v = Vector::from([Complex::new(1, 2), Complex::new(0, -1)]);
+    let w = Vector::from([Complex::new(3, 0), Complex::new(1, 1)]);
+    assert_eq!(v.inner(&v), Complex::new(v.norm_sqr(), 0));
+    assert_eq!(v.inner(&w), Complex::new(2, -5));
+    assert_eq!((&v).dot(&w), Complex::new(4, 5));
+    assert_eq!(Vector::from([1, 2, 3]).dot(Vector::from([4, 5, 6])), 32);
+}
+
+#[test]
+fn ratio() {
+    let a = Ratio::new(1, 3);
+    let b = Ratio::new(-2, 4);
+
+    assert_eq!(b, Ratio::new(1, -2));
+    assert_eq!((b.numer(), b.denom()), (-1, 2));
+    assert_eq!(a + b, Ratio::new(-1, 6));
+    assert_eq!(a - b, Ratio::new(5, 6));
+    assert_eq!(a * b, Ratio::new(-1, 6));
+    assert_eq!(a / b, Ratio::new(-2, 3));
+    assert_eq!(a * Ratio::ZERO, Ratio::ZERO);
+    assert_eq!(a.pow(-2), Ratio::from(9));
+    assert_eq!(b.pow(3), Ratio::new(-1, 8));
+    assert!(b < a);
+    assert!(Ratio::new(2, 3) > Ratio::new(3, 5));
+    assert_eq!(format!("{} {}", a, Ratio::from(4)), "1/3 4");
+
+    let m = Matrix::from([
+        [2, 1, 1],
+        [1, 3, 2],
+        [1, 0, 0],
+    ]).map(Ratio::<i64>::from);
+
+    assert_eq!(m.determinant(), Ratio::from(-1));
+    let inverse = m.inverse();
+    assert_eq!((&m).dot(&inverse), Matrix::unit());
+    assert_eq!(inverse, Matrix::from([
+        [0, 0, 1],
+        [-2, 1, 3],
+        [3, -1, -5],
+    ]).map(Ratio::from));
+
+    let x = m.solve(&Vector::from([Ratio::from(1), Ratio::from(2), Ratio::new(1, 3)])).unwrap();
+    assert_eq!(x, [Ratio::new(1, 3), Ratio::from(1), Ratio::new(-2, 3)]);
+
+    let h = Matrix::from([
+        [Ratio::new(1, 1), Ratio::new(1, 2), Ratio::new(1, 3)],
+        [Ratio::new(1, 2), Ratio::new(1, 3), Ratio::new(1, 4)],
+        [Ratio::new(1, 3), Ratio::new(1, 4), Ratio::new(1, 5)],
+    ]);
+    assert_eq!(h.determinant(), Ratio::new(1, 2160));
+    assert_eq!((&h).dot(&h.inverse()), Matrix::unit());
+
+    let singular = Matrix::from([[1, 2], [2, 4]]).map(Ratio::<i64>::from);
+    assert_eq!(singular.determinant(), Ratio::ZERO);
+    assert!(singular.try_inverse().is_none());
+
+    assert_eq!(Matrix::from([[3, 8, 1], [4, 6, 2], [0, 5, 7]]).determinant(), -108);
+    assert_eq!(Matrix::<0, 0, i32>::default().determinant(), 1);
+}
+
+#[test]
+fn complex_elimination() {
+    let i = Complex::<f64>::I;
+    let diag = Matrix::from([[i, Complex::ZERO], [Complex::ZERO, i]]);
+    assert_eq!(diag.determinant(), -Complex::ONE);
+    assert_eq!(diag.inverse(), Matrix::from([[-i, Complex::ZERO], [Complex::ZERO, -i]]));
+    assert_eq!(diag.solve(&Vector::from([i, Complex::ONE])), Some(Vector::from([Complex::ONE, -i])));
+
+    let m = Matrix::from([
+        [i, Complex::ONE],
+        [Complex::new(2.0, 0.0), Complex::new(1.0, 1.0)],
+    ]);
+    assert_eq!(m.determinant(), Complex::new(-3.0, 1.0));
+    assert_eq!(m.solve(&Vector::from([Complex::new(0.0, 2.0), Complex::new(1.0, 1.0)])), Some(Vector::from([Complex::ONE, i])));
+
+    let singular = Matrix::from([[i, Complex::ONE], [-Complex::ONE, i]]);
+    assert_eq!(singular.determinant(), Complex::ZERO);
+    assert!(singular.try_inverse().is_none());
+}
+
+#[test]
+fn fixed() {
+    type Q = Fixed<i32, 16>;
+
+    let a = Q::from_f64(1.5);
+    let b = Q::from_int(-2);
+
+    assert_eq!(a.to_bits(), 3 << 15);
+    assert_eq!((a + b).to_f64(), -0.5);
+    assert_eq!((a * b).to_f64(), -3.0);
+    assert_eq!((b / a).to_f64(), -1.3333282470703125);
+    assert_eq!((Q::ONE / Q::from_int(3)).to_bits(), 21845);
+    assert_eq!((Q::from_int(2) / Q::from_int(3)).to_bits(), 43691);
+    assert_eq!((-a).to_int(), -2);
+    assert_eq!(a.pow(3).to_f64(), 3.375);
+    assert_eq!(Q::from_usize(7), Q::from_int(7));
+    assert_eq!(format!("{}", a), "1.5");
+
+    assert_eq!(Q::MAX + Q::ONE, Q::MAX);
+    assert_eq!(Q::MIN - Q::ONE, Q::MIN);
+    assert_eq!(Q::from_int(30000) * Q::from_int(30000), Q::MAX);
+    assert_eq!(Q::from_int(40000), Q::MAX);
+    assert_eq!(Fixed::<i8, 4>::from_usize(1000), Fixed::<i8, 4>::MAX);
+
+    // rounding to nearest, ties away from zero
+    let half = Fixed::<i8, 1>::from_bits(1);
+    assert_eq!((half * half).to_bits(), 1);
+    assert_eq!((-half * half).to_bits(), -1);
+
+    let m = Matrix::from([[a, b], [b, a]]);
+    let m2 = m.clone() * Q::from_int(2);
+    assert_eq!(m2.map(Q::to_f64), [[3.0, -4.0], [-4.0, 3.0]]);
+}
+
+#[test]
+fn dual() {
+    let x: Dual = Dual::variable(2.0);
+    assert_eq!(x * x + Dual::constant(3.0) * x, Dual::new(10.0, 7.0));
+    assert_eq!(Dual::constant(1.0) / x, Dual::new(0.5, -0.25));
+    assert_eq!(x.powi(3), Dual::new(8.0, 12.0));
+    assert_eq!(x.pow(3), x * x * x);
+    assert_eq!(format!("{}", Dual::new(1, -2)), "1-2ε");
+
+    assert_eq!(derivative(|x: Dual| x.sin(), 0.0), 1.0);
+    assert_eq!(derivative(|x: Dual| x.exp() * x, 0.0), 1.0);
+    assert!((derivative(|x: Dual| x.sqrt().ln(), 4.0) - 0.125).abs() < 1e-15);
+
+    let grad = gradient(|v: &Vector<2, Dual>| v[0] * v[0] * v[1] + v[1].sin(), &Vector::from([3.0, 0.0]));
+    assert_eq!(grad, [0.0, 10.0]);
+
+    // polar to cartesian
+    let jac = jacobian(
+        |v: &Vector<2, Dual>| Vector::from([v[0] * v[1].cos(), v[0] * v[1].sin()]),
+        &Vector::from([2.0, 0.0]),
+    );
+    assert_eq!(jac, [
+        [1.0, 0.0],
+        [0.0, 2.0],
+    ]);
+
+    let jac = jacobian(
+        |v| Vector::from([v[0] * v[1], v[0] + v[1], v[2] * v[2]]),
+        &Vector::from([2, 5, 3]),
+    );
+    assert_eq!(jac, [
+        [5, 2, 0],
+        [1, 1, 0],
+        [0, 0, 6],
+    ]);
+}
+
+#[test]
+fn interval() {
+    type I = Interval<f64>;
+
+    let a = I::new(1.0, 2.0);
+    let b = I::new(-3.0, 0.5);
+
+    assert_eq!(a + b, I::new(-2.0, 2.5));
+    assert_eq!(a - b, I::new(0.5, 5.0));
+    assert_eq!(a * b, I::new(-6.0, 1.0));
+    assert_eq!(b / a, I::new(-3.0, 0.5));
+    assert_eq!(a / b, I::ENTIRE);
+    assert_eq!(b.pow(2), I::new(0.0, 9.0));
+    assert_eq!(b.pow(3), I::new(-27.0, 0.125));
+
+    // underflow must not collapse to a point
+    let tiny: I = Interval::point(1e-200) * Interval::point(1e-200);
+    assert!(tiny.lo() < 0.0 && tiny.hi() > 0.0);
+    let sub = I::point(3e-320) * I::point(0.3);
+    assert!(sub.lo() < sub.hi());
+    assert!(sub.contains(3e-320 * 0.3));
+    let quot = I::point(1e-300) / I::point(1e300);
+    assert!(quot.lo() < 0.0 && quot.hi() > 0.0);
+    let root = I::point(1e-320).sqrt();
+    assert!(root.lo() < root.hi());
+    assert_eq!(I::point(1e-200) / I::point(f64::INFINITY), I::ZERO);
+    assert_eq!(Interval::point(1.0), I::ONE);
+    assert_eq!(Interval::<f32>::point(2.0).hi(), 2.0);
+    assert_eq!(a.pow(-1), I::new(0.5, 1.0));
+    assert_eq!(I::new(4.0, 9.0).sqrt(), I::new(2.0, 3.0));
+
+    let third = I::ONE / I::from(3.0);
+    assert!(third.lo() < third.hi());
+    assert_eq!(third.hi().next_down(), third.lo());
+    assert!(third.contains(1.0 / 3.0));
+    assert!((third * I::from(3.0)).contains(1.0));
+
+    let tenth = I::ONE / I::from(10.0);
+    let sum = (0..10).fold(I::ZERO, |acc, _| acc + tenth);
+    assert!(sum.contains(1.0));
+    assert!(sum.width() < 1e-14);
+
+    assert!(a < I::new(2.5, 3.0));
+    assert!(a > b);
+    assert_eq!(a.partial_cmp(&I::new(0.0, 1.5)), None);
+    assert_eq!(a.hull(&b), I::new(-3.0, 2.0));
+    assert_eq!(a.intersection(&b), None);
+    assert_eq!(b.intersection(&a.hull(&b)), Some(b));
+    assert_eq!(format!("{}", a), "[1, 2]");
+
+    let m = Matrix::from([[a, b], [b, a]]);
+    let v = (&m).dot(&m);
+    assert!(v[(0, 0)].contains(1.0 * 1.0 + -3.0 * -3.0));
+    assert!(v[(1, 0)].contains(2.0 * 0.5 + 0.5 * 2.0));
+}
+
+#[test]
+fn real() {
+    fn hypot_generic<T: Real>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    fn max_abs<T: Abs>(values: &[T]) -> T {
+        values.iter().fold(T::ZERO, |acc, &value| {
+            let value = value.abs();
+            if value > acc { value } else { acc }
+        })
+    }
+
+    assert_eq!(hypot_generic(3.0f32, 4.0), 5.0);
+    assert_eq!(hypot_generic(3.0f64, 4.0), 5.0);
+    assert_eq!(<f64 as Real>::max_value(), f64::MAX);
+    assert!(Real::is_nan(<f32 as Real>::NAN));
+    assert_eq!(<f64 as Real>::from_f64(0.5).to_f64(), 0.5);
+    assert_eq!(max_abs(&[3, -7, 5]), 7);
+    assert_eq!(max_abs(&[3u8, 7, 5]), 7);
+    assert_eq!(Signed::signum(-5i16), -1);
+    assert!(Signed::is_negative(-0.5f64));
+
+    let v: Vector<2> = Vector::from([3.0, 4.0]);
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(v.normalized(), [0.6, 0.8]);
+    assert_eq!(Matrix::from([[1.0f32, 2.0], [2.0, 4.0]]).frobenius_norm(), 5.0);
+
+    assert_eq!(Complex::from_polar(2.0f64, 0.0), Complex::new(2.0, 0.0));
+    assert_eq!(Complex::new(3.0f32, 4.0).norm(), 5.0);
+}
+
+#[test]
+fn modp() {
+    type F = ModP<7>;
+
+    assert_eq!(F::new(5) + F::new(4), F::new(2));
+    assert_eq!(F::new(2) - F::new(5), F::new(4));
+    assert_eq!(F::new(3) * F::new(5), F::new(1));
+    assert_eq!(F::new(1) / F::new(3), F::new(5));
+    assert_eq!(-F::new(3), F::new(4));
+    assert_eq!(F::from_i64(-1), F::new(6));
+    assert_eq!(F::new(3).pow(6u32), F::ONE);
+    assert_eq!(F::new(3).pow(-1), F::new(5));
+    assert_eq!(ModP::<8>::new(2).inverse(), None);
+
+    // largest prime below 2^64, above i64::MAX
+    type G = ModP<18446744073709551557>;
+    assert_eq!(G::from_i64(-1).value(), G::P - 1);
+    assert_eq!(G::from_i64(i64::MIN), -G::new(1 << 63));
+    assert_eq!(G::from_i64(-1) * G::from_i64(-1), G::ONE);
+
+    let m = Matrix::from([
+        [1, 2, 3],
+        [4, 5, 6],
+        [7, 8, 10],
+    ]).map(F::new);
+    assert_eq!(m.determinant(), F::from_i64(-3));
+    assert_eq!((&m).dot(&m.inverse()), Matrix::unit());
+
+    let singular = Matrix::from([[1, 2], [3, 6]]).map(F::new);
+    assert_eq!(singular.determinant(), F::ZERO);
+    assert!(singular.try_inverse().is_none());
+}
+
+#[test]
+fn bitmatrix() {
+    let a = BitMatrix::from([
+        [true, false, true],
+        [false, true, true],
+    ]);
+    let b = BitMatrix::from([
+        [true, true, false],
+        [false, true, false],
+    ]);
+
+    assert_eq!(&a + &b, BitMatrix::from([[false, true, true], [false, false, true]]));
+    assert_eq!(&a * &b, BitMatrix::from([[true, false, false], [false, true, false]]));
+    assert_eq!(a.get(2, 1), Some(true));
+    assert_eq!(a.get(3, 1), None);
+    assert_eq!(a.count_ones(), 4);
+    assert_eq!(format!("{}", a), "101\n011");
+    assert_eq!(a.transpose(), BitMatrix::from([[true, false], [false, true], [true, true]]));
+    assert_eq!(a.rank(), 2);
+
+    // Hamming(7,4) generator times a message gives a codeword with zero syndrome
+    let generator = BitMatrix::from([
+        [true, false, false, false, true, true, false],
+        [false, true, false, false, true, false, true],
+        [false, false, true, false, false, true, true],
+        [false, false, false, true, true, true, true],
+    ]);
+    let parity = BitMatrix::from([
+        [true, true, false, true, true, false, false],
+        [true, false, true, true, false, true, false],
+        [false, true, true, true, false, false, true],
+    ]);
+    let message = BitMatrix::from([[true, false, true, true]]);
+    let codeword = message.dot(&generator);
+    assert_eq!(codeword, BitMatrix::from([[true, false, true, true, false, true, false]]));
+    assert_eq!(parity.dot(&codeword.transpose()), BitMatrix::<1, 3>::new());
+
+    let square = BitMatrix::from([
+        [true, true, false],
+        [false, true, true],
+        [false, false, true],
+    ]);
+    assert!(square.determinant());
+    assert_eq!((&square).dot(&square.try_inverse().unwrap()), BitMatrix::identity());
+    assert_eq!(BitMatrix::from(&square.to_matrix()), square);
+
+    let mut wide = BitMatrix::<100, 2>::new();
+    wide.set(99, 0, true);
+    wide.set(64, 1, true);
+    assert_eq!(wide.transpose().get(0, 99), Some(true));
+    assert_eq!(wide.rank(), 2);
+}
+
+#[test]
+fn half() {
+    assert_eq!(f16::from_f32(1.0).to_bits(), 0x3c00);
+    assert_eq!(f16::from_f32(-2.0).to_bits(), 0xc000);
+    assert_eq!(f16::from_f32(65504.0), f16::MAX);
+    assert_eq!(f16::from_f32(65520.0), f16::INFINITY);
+    assert_eq!(f16::from_f32(2f32.powi(-24)).to_bits(), 0x0001);
+    assert_eq!(f16::from_bits(0x0001).to_f32(), 2f32.powi(-24));
+    assert_eq!(f16::from_f32(1.0 + 1.0 / 2048.0), f16::ONE); // tie to even
+    assert_eq!(f16::from_f32(1.0 + 3.0 / 2048.0).to_bits(), 0x3c02);
+    assert!(f16::from_f32(f32::NAN).is_nan());
+
+    // f64 ties round once, not twice through f32
+    assert_eq!(f16::from_f64(1.0 + 2f64.powi(-11)), f16::ONE);
+    assert_eq!(f16::from_f64(1.0 + 3.0 * 2f64.powi(-11)).to_bits(), 0x3c02);
+    assert_eq!(f16::from_f64(1.0 + 2f64.powi(-11) + 2f64.powi(-40)).to_bits(), 0x3c01);
+    assert_eq!(f16::from_f64(2f64.powi(-25)).to_bits(), 0x0000);
+    assert_eq!(f16::from_f64(2f64.powi(-25) + 2f64.powi(-60)).to_bits(), 0x0001);
+    assert_eq!(f16::from_f64(3.0 * 2f64.powi(-25)).to_bits(), 0x0002);
+    assert_eq!(f16::from_f64(65504.0), f16::MAX);
+    assert_eq!(f16::from_f64(65520.0), f16::INFINITY);
+    assert_eq!(f16::from_f64(-1e-300).to_bits(), 0x8000);
+    assert!(f16::from_f64(f64::NAN).is_nan());
+    assert_eq!(f16::from_f64(f64::NEG_INFINITY), f16::NEG_INFINITY);
+    assert_eq!(bf16::from_f64(1.0 + 2f64.powi(-8)), bf16::ONE);
+    assert_eq!(bf16::from_f64(1.0 + 2f64.powi(-8) + 2f64.powi(-30)).to_bits(), 0x3f81);
+    assert_eq!(bf16::from_f64(2f64.powi(-134) + 2f64.powi(-160)).to_bits(), 0x0001);
+    assert_eq!(bf16::from_f64(1e300), bf16::INFINITY);
+    assert!(bf16::from_f64(f64::NAN).is_nan());
+    assert!(Vector::from([1.0 + 2f64.powi(-11) + 2f64.powi(-40)]).try_cast::<f16>().is_err());
+    assert_eq!(Vector::from([1.0 + 2f64.powi(-10)]).try_cast::<f16>(), Ok(Vector::from([f16::from_bits(0x3c01)])));
+
+    assert_eq!(bf16::from_f32(1.0), bf16::ONE);
+    assert_eq!(bf16::from_f32(3.140625).to_f32(), 3.140625);
+    assert_eq!(bf16::from_f32(f32::MAX), bf16::INFINITY);
+
+    let a = f16::from_f32(1.5);
+    let b = f16::from_f32(0.25);
+    assert_eq!(a + b, f16::from_f32(1.75));
+    assert_eq!(a * b, f16::from_f32(0.375));
+    assert_eq!(-a, f16::from_f32(-1.5));
+    assert_eq!(a.pow(2), f16::from_f32(2.25));
+    assert_eq!(f16::from_usize(2048), f16::from_f32(2048.0));
+    assert!(b < a);
+    assert_eq!(format!("{}", a), "1.5");
+
+    let m = Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]);
+    let h = m.map(f16::from_f32);
+    assert_eq!(h.map(f32::from), m);
+    assert_eq!(&h + &h, (&m + &m).map(f16::from_f32));
+
+    // 2048 + 1 is not representable in f16, so naive accumulation gets stuck
+    let v = Vector::from([f16::ONE; 4096]);
+    assert_eq!(v.sum(), f16::from_f32(2048.0));
+    assert_eq!(v.widening_sum(), 4096.0);
+    assert_eq!(v.widening_dot(&v), 4096.0);
+    assert_eq!(Vector::from([f16::from_f32(300.0); 2]).widening_product(), 90000.0);
+
+    let m = Matrix::from([[bf16::ONE, bf16::ONE]]);
+    let t = Matrix::from([[bf16::ONE], [bf16::ONE]]);
+    assert_eq!(m.widening_dot(&t), Matrix::from([[2.0f32]]));
+    assert_eq!(m.widening_sum(), Vector::from([2.0f32]));
+    assert_eq!(m.widening_sum_column(1), 1.0);
+    assert_eq!(m.widening_product(), Vector::from([1.0f32]));
+    assert_eq!(t.widening_product_column(0), 1.0);
+}
+
+#[test]
+fn overflow() {
+    use std::num::{Wrapping, Saturating};
+
+    let a = Matrix::from([[200u8, 100], [50, 255]]);
+    let b = Matrix::from([[100u8, 100], [50, 1]]);
+
+    assert_eq!(a.checked_add(&b), None);
+    assert_eq!(a.checked_sub(&b), Some(Matrix::from([[100u8, 0], [0, 254]])));
+    assert_eq!(a.checked_div(0), None);
+    assert_eq!(a.wrapping_add(&b), Matrix::from([[44u8, 200], [100, 0]]));
+    assert_eq!(a.saturating_add(&b), Matrix::from([[255u8, 200], [100, 255]]));
+    assert_eq!(a.saturating_sub(60), Matrix::from([[140u8, 40], [0, 195]]));
+    assert_eq!(a.saturating_mul(2), Matrix::from([[255u8, 200], [100, 255]]));
+
+    let m = Matrix::from([[100i8, 1], [1, 1]]);
+    assert_eq!(m.checked_dot(&m), None);
+    assert_eq!(m.wrapping_dot(&m), Matrix::from([[(10001i32 as i8), 101], [101, 2]]));
+    assert_eq!(m.saturating_dot(&m), Matrix::from([[127i8, 101], [101, 2]]));
+    let m = Matrix::from([[1i8, 2], [3, 4]]);
+    assert_eq!(m.checked_dot(&m), Some(Matrix::from([[7i8, 10], [15, 22]])));
+
+    let v = Vector::from([i32::MAX, 1]);
+    assert_eq!(v.checked_add(1), None);
+    assert_eq!(v.wrapping_add(&Vector::from([1, 1])), Vector::from([i32::MIN, 2]));
+    assert_eq!(v.saturating_mul(-2), Vector::from([i32::MIN, -2]));
+    assert_eq!(v.checked_dot(&v), None);
+    assert_eq!(Vector::from([3, 4]).checked_dot(&Vector::from([5, 6])), Some(39));
+    assert_eq!(v.wrapping_dot(&v), 2);
+    assert_eq!(v.saturating_dot(&v), i32::MAX);
+
+    // saturates once at the end, independent of the element order
+    assert_eq!(Vector::from([100i8, -100]).saturating_dot(&Vector::from([2, 1])), 100);
+    assert_eq!(Vector::from([-100i8, 100]).saturating_dot(&Vector::from([1, 2])), 100);
+    assert_eq!(Vector::from([100i8, 100]).saturating_dot(&Vector::from([2, 1])), 127);
+    assert_eq!(Vector::from([200u8, 0]).saturating_dot(&Vector::from([2, 9])), 255);
+    let m = Matrix::from([[100i8, -100], [-128, 1]]);
+    let t = Matrix::from([[2i8, 1], [1, 1]]);
+    assert_eq!(m.saturating_dot(&t), Matrix::from([[100i8, 0], [-128, -127]]));
+
+    let w = Matrix::from([[Wrapping(250u8), Wrapping(3)]]);
+    assert_eq!(&w + &w, Matrix::from([[Wrapping(244u8), Wrapping(6)]]));
+    assert_eq!(Pow::pow(Wrapping(2u8), 9), Wrapping(0));
+
+    let s = Vector::from([Saturating(250u8), Saturating(3)]);
+    assert_eq!(&s + &s, Vector::from([Saturating(255u8), Saturating(6)]));
+    assert_eq!(Saturating::<u8>::from_usize(1000), Saturating(255));
+}
+
+#[test]
+fn try_avg() {
+    assert_eq!(i8::try_from_usize(300), Err(TryFromUSizeError { value: 300 }));
+    assert_eq!(u16::try_from_usize(300), Ok(300));
+    assert_eq!(TryFromUSizeError { value: 300 }.to_string(), "300 is out of range for the target type");
+
+    let m = Matrix::from([[100i8; 300]]);
+    assert_eq!(m.try_avg(), Ok(Vector::from([100i8])));
+    assert_eq!(m.widening_sum(), Vector::from([30000i64]));
+    assert_eq!(m.try_avg_column(7), Ok(100));
+
+    let v = Vector::from([250u8, 251, 255]);
+    assert_eq!(v.try_avg(), Ok(252));
+    assert_eq!(Vector::from([-3i8, -4]).try_avg(), Ok(-3));
+    assert_eq!(Vector::from([1.0f32, 2.0]).try_avg(), Ok(1.5));
+
+    let m = Matrix::from([[i32::MAX, i32::MAX], [1, 3]]);
+    assert_eq!(m.try_avg(), Ok(Vector::from([i32::MAX, 2])));
+    assert_eq!(m.try_avg_column(0), Ok(i32::MAX / 2 + 1));
+
+    assert_eq!(Vector::<0, i32>::from([]).try_avg(), Err(TryAvgError::Empty));
+    assert_eq!(Matrix::<0, 2, u8>::default().try_avg(), Err(TryAvgError::Empty));
+    assert_eq!(Matrix::<2, 0, u8>::default().try_avg(), Ok(Vector::from([])));
+    assert_eq!(Matrix::<2, 0, u8>::default().try_avg_column(0), Err(TryAvgError::Empty));
+    assert_eq!(Vector::<0, f32>::from([]).try_avg(), Err(TryAvgError::Empty));
+    assert_eq!(TryAvgError::Empty.to_string(), "average of no values");
+    assert_eq!(TryAvgError::from(TryFromUSizeError { value: 300 }).to_string(), "300 is out of range for the target type");
+}
+
+#[test]
+fn cast() {
+    let m = Matrix::from([[1u8, 2], [3, 255]]);
+    assert_eq!(m.cast::<f32>(), Matrix::from([[1.0f32, 2.0], [3.0, 255.0]]));
+    assert_eq!(m.cast::<i16>().cast_lossy::<i8>(), Matrix::from([[1i8, 2], [3, -1]]));
+
+    let m = Matrix::from([[0.0f64, 127.6], [-1.0, 300.0]]);
+    assert_eq!(m.cast_lossy::<u8>(), Matrix::from([[0u8, 127], [0, 255]]));
+    assert_eq!(m.try_cast::<u8>(), Err(TryCastError { x: 1, y: 0, value: 127.6 }));
+    assert_eq!(m.map(f64::round).try_cast::<u8>(), Err(TryCastError { x: 0, y: 1, value: -1.0 }));
+    assert_eq!(
+        m.map(|value| value.round().clamp(0.0, 255.0)).try_cast::<u8>(),
+        Ok(Matrix::from([[0u8, 128], [0, 255]])));
+    assert_eq!(
+        TryCastError { x: 1, y: 0, value: 127.6 }.to_string(),
+        "element (1, 0) = 127.6 can't be converted exactly");
+
+    let v = Vector::from([16777216i32, 16777217]);
+    assert_eq!(v.cast::<f64>(), Vector::from([16777216.0, 16777217.0]));
+    assert_eq!(v.try_cast::<f32>(), Err(TryCastError { x: 1, y: 0, value: 16777217 }));
+    assert_eq!(Vector::from([-1i32]).try_cast::<u32>(), Err(TryCastError { x: 0, y: 0, value: -1 }));
+    assert_eq!(Vector::from([u64::MAX]).try_cast::<f64>(), Err(TryCastError { x: 0, y: 0, value: u64::MAX }));
+    assert!(Vector::from([9223372036854775808.0f64]).try_cast::<i64>().is_err());
+    assert_eq!(Vector::from([-9223372036854775808.0f64]).try_cast::<i64>(), Ok(Vector::from([i64::MIN])));
+    assert!(Vector::from([f64::NAN]).try_cast::<i32>().is_err());
+    assert!(Vector::from([0.1f64]).try_cast::<f32>().is_err());
+    assert_eq!(Vector::from([0.5f64]).try_cast::<f32>(), Ok(Vector::from([0.5f32])));
+
+    let v = Vector::from([1.5f32, 0.1]);
+    assert_eq!(v.cast_lossy::<f16>().cast::<f32>(), Vector::from([1.5, 0.099975586]));
+    assert_eq!(v.try_cast::<f16>(), Err(TryCastError { x: 1, y: 0, value: 0.1 }));
+}
+
+#[test]
+fn semiring() {
+    let m = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!(m.semiring_dot::<Standard>(&m), (&m).dot(&m));
+    assert_eq!(m.semiring_pow::<Standard>(3), Matrix::from([[37, 54], [81, 118]]));
+
+    const INF: u32 = u32::MAX;
+    // 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (5)
+    let graph = Matrix::from([
+        [0,   4,   1,   INF],
+        [INF, 0,   INF, 5  ],
+        [INF, 2,   0,   INF],
+        [INF, INF, INF, 0  ],
+    ]);
+    assert_eq!(graph.semiring_pow::<MinPlus>(3), Matrix::from([
+        [0,   3,   1,   8  ],
+        [INF, 0,   INF, 5  ],
+        [INF, 2,   0,   7  ],
+        [INF, INF, INF, 0  ],
+    ]));
+
+    let graph = Matrix::from([
+        [f64::NEG_INFINITY, 4.0,               1.0              ],
+        [f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY],
+        [f64::NEG_INFINITY, 2.0,               f64::NEG_INFINITY],
+    ]);
+    assert_eq!(graph.semiring_dot::<MaxPlus>(&graph).data()[1], 3.0);
+    assert_eq!(Matrix::<3, 3, f64>::semiring_identity::<MaxPlus>().data()[..3], [0.0, f64::NEG_INFINITY, f64::NEG_INFINITY]);
+
+    let m = Matrix::from([[0i32, 3], [2, 0]]);
+    assert_eq!(m.semiring_pow::<MaxPlus>(2), Matrix::from([[5, 3], [2, 5]]));
+    assert_eq!(m.semiring_pow::<MinPlus>(2), Matrix::from([[0, 3], [2, 0]]));
+
+    // large finite weights saturate to infinity instead of wrapping
+    let m = Matrix::from([[i32::MAX - 1, 0], [0, 2]]);
+    assert_eq!(m.semiring_dot::<MinPlus>(&Matrix::from([[2, i32::MAX], [i32::MAX, 0]])).data()[0], i32::MAX);
+    assert_eq!(<MaxPlus as Semiring<i32>>::mul(i32::MIN + 1, -2), i32::MIN);
+
+    let edges = Matrix::from([
+        [0u8, 1, 0, 0],
+        [0, 0, 7, 0],
+        [0, 0, 0, 0],
+        [1, 0, 0, 0],
+    ]);
+    assert_eq!(edges.semiring_pow::<Boolean>(2), Matrix::from([
+        [0u8, 0, 1, 0],
+        [0, 0, 0, 0],
+        [0, 0, 0, 0],
+        [0, 1, 0, 0],
+    ]));
+    let reach = (&edges + &Matrix::unit()).semiring_pow::<Boolean>(3);
+    assert_eq!(reach, Matrix::from([
+        [1u8, 1, 1, 0],
+        [0, 1, 1, 0],
+        [0, 0, 1, 0],
+        [1, 1, 1, 1],
+    ]));
+}
+
+#[test]
+fn approx_eq() {
+    assert_eq!(1.0f32.ulps(1.0), 0);
+    assert_eq!(1.0f32.ulps(f32::from_bits(1.0f32.to_bits() + 3)), 3);
+    assert_eq!(0.0f64.ulps(-0.0), 0);
+    assert_eq!(f64::from_bits(1).ulps(-f64::from_bits(1)), 2);
+    assert_eq!(f32::NAN.ulps(f32::NAN), u64::MAX);
+
+    let a = Matrix::from([[1.0f64, 2.0], [1e-12, 1000.0]]);
+    let b = Matrix::from([[1.0, 2.0 + 1e-10], [0.0, 1000.0 + 1e-9]]);
+    assert!(a.approx_eq(&b, 1e-9, 0.0));
+    assert!(!a.approx_eq(&b, 1e-11, 0.0));
+    assert!(!a.approx_eq(&b, 0.0, 1e-9));
+    let (x, y, diff) = a.approx_mismatch(&b, 1e-13, 1e-11).unwrap();
+    assert_eq!((x, y), (1, 0));
+    assert!((diff - 1e-10).abs() < 1e-15);
+    assert_matrix_approx_eq!(a, b, 1e-9, 0.0);
+    assert_matrix_approx_eq!(a, b, 1e-11, 1e-8);
+
+    let c = a.map(|value| f64::from_bits(value.to_bits() + 2));
+    assert!(a.ulps_eq(&c, 2));
+    assert_eq!(a.ulps_mismatch(&c, 1), Some((0, 0, 2)));
+    assert_matrix_approx_eq!(a, c, ulps = 2);
+
+    let u = Vector::from([1.0f32, f32::NAN, 3.0]);
+    let v = Vector::from([1.5f32, 2.0, 3.0]);
+    assert_eq!(u.approx_mismatch(&v, 0.1, 0.0).map(|(index, _)| index), Some(1));
+    assert_eq!(v.approx_mismatch(&Vector::from([1.0, 2.0, 3.0]), 0.1, 0.0), Some((0, 0.5)));
+    assert_eq!(v.ulps_mismatch(&v, 0), None);
+    assert_vector_approx_eq!(v, Vector::from([1.5, 2.0, 3.000001]), 0.0, 1e-6);
+    assert_vector_approx_eq!(v, Vector::from([1.5, 2.0, 3.0]), ulps = 0);
+    assert!(Vector::from([f64::INFINITY]).approx_eq(&Vector::from([f64::INFINITY]), 0.0, 0.0));
+    assert!(!Vector::from([f64::INFINITY]).approx_eq(&Vector::from([1.0]), 0.0, 1e-9));
+    assert!(!Vector::from([1.0]).approx_eq(&Vector::from([f64::NEG_INFINITY]), 1.0, 1.0));
+    assert_eq!(
+        Vector::from([f64::INFINITY]).approx_mismatch(&Vector::from([f64::NEG_INFINITY]), 0.0, 1e-9),
+        Some((0, f64::INFINITY)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "element (1, 0) differs by 0.5: 2.0 vs 2.5")]
+fn assert_matrix_approx_eq_fails() {
+    assert_matrix_approx_eq!(
+        Matrix::from([[1.0, 2.0], [3.0, 4.0]]),
+        Matrix::from([[1.1, 2.5], [3.0, 4.0]]),
+        0.2, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "element 2 is 4 ulps apart (max 1)")]
+fn assert_vector_approx_eq_fails() {
+    assert_vector_approx_eq!(
+        Vector::from([1.0f32, 2.0, 3.0]),
+        Vector::from([1.0, 2.0, f32::from_bits(3.0f32.to_bits() - 4)]),
+        ulps = 1);
+}
+
+#[test]
+fn stats() {
+    use matrix::ops::MatrixAggregate;
+
+    let v = Vector::from([3.0, 1.0, f64::NAN, 2.0]);
+    assert!(v.median().is_nan());
+    let v = Vector::from([3.0, -1.0, 2.0]);
+    assert_eq!(v.median(), 2.0);
+    assert_eq!(Vector::from([5, 1, 4, 2]).median(), 3);
+    assert_eq!(Vector::from([200u8, 100]).median(), 150);
+    assert_eq!(Vector::from([-100i8, 100, 120, -128]).median(), 0);
+    assert_eq!(Vector::from([f64::MAX, f64::MAX]).median(), f64::MAX);
+
+    let v = Vector::from([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    assert_eq!(v.variance(0), 4.0);
+    assert_eq!(v.std_dev(0), 2.0);
+    assert_eq!(v.variance(1), 32.0 / 7.0);
+
+    let v = Vector::from([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(v.quantile(0.0, Interpolation::Linear), 1.0);
+    assert_eq!(v.quantile(1.0, Interpolation::Linear), 4.0);
+    assert_eq!(v.quantile(0.4, Interpolation::Linear), 2.2);
+    assert_eq!(v.quantile(0.4, Interpolation::Lower), 2.0);
+    assert_eq!(v.quantile(0.4, Interpolation::Higher), 3.0);
+    assert_eq!(v.quantile(0.4, Interpolation::Nearest), 2.0);
+    assert_eq!(v.quantile(0.5, Interpolation::Nearest), 3.0);
+    assert_eq!(v.quantile(0.5, Interpolation::Midpoint), 2.5);
+    assert_eq!(v.quantile(0.5, Interpolation::default()), 2.5);
+
+    assert_eq!(Vector::from([3, 1, 3, 2, 1]).mode(), 1);
+    assert_eq!(Vector::from([3, 1, 3, 2]).mode(), 3);
+
+    let m = Matrix::from([
+        [1.0f32, 5.0, 3.0],
+        [2.0,    2.0, 8.0],
+    ]);
+    assert_eq!(m.median(), Vector::from([3.0, 2.0]));
+    assert_eq!(m.by_column().median(), Vector::from([1.5, 3.5, 5.5]));
+    assert_eq!(m.variance(0), Vector::from([8.0 / 3.0, 8.0]));
+    assert_eq!(m.by_column().variance(1), Vector::from([0.5, 4.5, 12.5]));
+    assert_eq!(m.by_column().std_dev(0), Vector::from([0.5, 1.5, 2.5]));
+    assert_eq!(m.quantile(1.0, Interpolation::Linear), Vector::from([5.0, 8.0]));
+    assert_eq!(m.clone().into_by_column().quantile(0.0, Interpolation::Linear), Vector::from([1.0, 2.0, 3.0]));
+
+    let m = Matrix::from([
+        [1, 2, 2],
+        [1, 7, 7],
+        [1, 2, 9],
+    ]);
+    assert_eq!(m.mode(), Vector::from([2, 7, 1]));
+    assert_eq!(m.by_column().mode(), Vector::from([1, 2, 2]));
+    assert_eq!(m.aggregate(|row| row.len()), Vector::from([3usize; 3]));
+
+    // only implements the required methods
+    struct Rows(Matrix<2, 2, i32>);
+
+    impl MatrixAggregate<2, 2, i32> for Rows {
+        fn fold<F, B>(&self, init: B, f: F) -> Vector<2, B>
+        where F: FnMut(B, i32) -> B, B: Number {
+            self.0.fold(init, f)
+        }
+
+        #[allow(deprecated)]
+        fn mean(&self) -> Vector<2, i32> {
+            self.0.mean()
+        }
+    }
+
+    let rows = Rows(Matrix::from([[4, 1], [2, 2]]));
+    assert_eq!(rows.aggregate(|row| row[0] - row[1]), Vector::from([3, 0]));
+    assert_eq!(rows.mode(), Vector::from([1, 2]));
+}
+
+#[test]
+#[should_panic(expected = "median of empty data")]
+fn median_empty() {
+    Vector::<0, f64>::default().median();
+}
+
+#[test]
+#[should_panic(expected = "mode of empty data")]
+fn mode_empty() {
+    use matrix::ops::MatrixAggregate;
+
+    Matrix::<0, 2, i32>::default().mode();
+}
+
+#[test]
+#[should_panic(expected = "ddof 2 is not less than the number of values 2")]
+fn variance_ddof() {
+    Vector::from([1.0, 2.0]).variance(2);
+}
+
+#[test]
+#[should_panic(expected = "quantile 1.5 is not in 0..=1")]
+fn quantile_out_of_range() {
+    Vector::from([1.0, 2.0]).quantile(1.5, Interpolation::Linear);
+}